@@ -2,10 +2,44 @@ use build_target::{Arch, Os};
 
 fn main() {
     let version_meta = rustc_version::version_meta().unwrap();
-    let use_nightly = cfg!(feature = "nightly") && !cfg!(feature = "stable")
-        || version_meta.channel == rustc_version::Channel::Nightly;
+    // nightly only features can only be enabled on a nightly compiler, the `nightly` feature is
+    // ignored on stable so that `--features nightly` still builds there.
+    let nightly_compiler = matches!(
+        version_meta.channel,
+        rustc_version::Channel::Nightly | rustc_version::Channel::Dev
+    );
+    let use_nightly = nightly_compiler && !cfg!(feature = "stable");
+    if cfg!(feature = "nightly") && !nightly_compiler {
+        cargo_emit::warning!("the `nightly` feature requires a nightly compiler and is ignored");
+    }
     if use_nightly {
-        cargo_emit::rustc_cfg!("nightly");
+        cargo_emit::rustc_cfg!("nightly_build");
+    }
+
+    // arities above 6 are opt-in via the `max-arity-12` feature or the `FN_PTR_MAX_ARITY` env var
+    const DEFAULT_MAX_ARITY: usize = 6;
+    const MAX_ARITY_LIMIT: usize = 16;
+    cargo_emit::rerun_if_env_changed!("FN_PTR_MAX_ARITY");
+    let requested_max_arity = std::env::var("FN_PTR_MAX_ARITY").ok().map(|value| {
+        value
+            .trim()
+            .parse::<usize>()
+            .unwrap_or_else(|_| panic!("FN_PTR_MAX_ARITY must be a number, got {value:?}"))
+    });
+    let mut max_arity = requested_max_arity.unwrap_or(DEFAULT_MAX_ARITY);
+    if cfg!(feature = "max-arity-12") {
+        max_arity = max_arity.max(12);
+    }
+    if max_arity > MAX_ARITY_LIMIT {
+        cargo_emit::warning!(
+            "FN_PTR_MAX_ARITY={} exceeds the supported maximum, using {}",
+            max_arity,
+            MAX_ARITY_LIMIT
+        );
+        max_arity = MAX_ARITY_LIMIT;
+    }
+    for arity in DEFAULT_MAX_ARITY + 1..=max_arity {
+        cargo_emit::rustc_cfg!("has_arity_{}", arity);
     }
 
     // from https://github.com/rust-lang/rust/blob/873122c006315e541c30809210089606877122c5/tests/ui/abi/unsupported.rs
@@ -0,0 +1,22 @@
+#![no_main]
+
+use fn_ptr::FnPtr;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|addr: usize| {
+    // function pointers are never null.
+    if addr == 0 {
+        return;
+    }
+
+    // SAFETY: the function pointers are never called.
+    let f = unsafe { <extern "C" fn(i32) -> i32>::from_addr(addr) };
+    assert_eq!(f.addr(), addr);
+    assert_eq!(f.as_ptr().addr(), addr);
+
+    let g: unsafe extern "C" fn(u32) -> u32 = unsafe { f.cast() };
+    assert_eq!(g.addr(), addr);
+    let h: fn() = unsafe { g.cast() };
+    assert_eq!(h.addr(), addr);
+    assert_eq!(f.as_unsafe().addr(), addr);
+});
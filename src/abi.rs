@@ -1,19 +1,31 @@
-use crate::AbiValue;
+use crate::{AbiMismatch, AbiValue, ReshapeParam};
 
 /// Type-level marker trait for function abi.
 ///
 /// Types implementing this trait represent a specific `extern "..."` abi.
 ///
-/// See [`Abi`] for the runtime representation.
+/// See [`AbiValue`] for the runtime representation.
 pub trait Abi {
     /// The exact abi string used in `extern "..."`.
     const STR: &'static str;
 
-    /// The runtime [`Abi`] that represent this marker type.
+    /// The runtime [`AbiValue`] that represent this marker type.
     const VALUE: AbiValue;
 
-    /// The runtime [`Abi`] that represent this marker type.
+    /// Whether unwinding after a panic is allowed inside a function with this abi
+    /// (see [`AbiValue::allows_unwind`]).
     const ALLOWS_UNWIND: bool = Self::VALUE.allows_unwind();
+
+    /// The abi this marker resolves to on the current target for a non-variadic function, or
+    /// [`None`] if it is not supported (see [`AbiValue::canonize`]).
+    ///
+    /// ```rust
+    /// # use fn_ptr::{Abi, AbiValue, abi};
+    /// assert_eq!(abi::C::CANONICAL, Some(AbiValue::C { unwind: false }));
+    /// # #[cfg(target_arch = "aarch64")]
+    /// assert_eq!(abi::Stdcall::CANONICAL, None);
+    /// ```
+    const CANONICAL: Option<AbiValue> = Self::VALUE.canonize(false);
 }
 
 /// Helper macro to implement [`Abi`].
@@ -29,6 +41,46 @@ macro_rules! define_abi_marker {
             const STR: &'static str = $lit;
             const VALUE: AbiValue = AbiValue::from_str_const($lit).unwrap();
         }
+
+        impl<T> ReshapeParam<T> for $name {
+            type Out = $name;
+        }
+
+        impl From<$name> for AbiValue {
+            fn from(_: $name) -> Self {
+                <$name as Abi>::VALUE
+            }
+        }
+
+        impl TryFrom<AbiValue> for $name {
+            type Error = AbiMismatch;
+
+            fn try_from(value: AbiValue) -> Result<Self, Self::Error> {
+                if value == <$name as Abi>::VALUE {
+                    Ok($name)
+                } else {
+                    Err(AbiMismatch {
+                        expected: <$name as Abi>::VALUE,
+                        found: value,
+                    })
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str($lit)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let visitor = crate::serde_impls::AbiVisitor(Some(<$name as Abi>::VALUE));
+                deserializer.deserialize_str(visitor).map(|_| $name)
+            }
+        }
     };
 }
 
@@ -66,6 +118,122 @@ define_abi_marker!(Win64Unwind, "win64-unwind");
 // Other
 define_abi_marker!(EfiApi, "efiapi");
 
+/// Marker trait for abis which do not allow unwinding (see [`Abi::ALLOWS_UNWIND`]).
+///
+/// A panic escaping a function with such an abi aborts the process.
+pub trait NoUnwind: Abi {}
+
+/// Helper trait implemented for an abi `Self` if a function with abi `Replacement` can safely be used in
+/// place of a function with abi `Self` with regards to unwinding.
+///
+/// This is the case if `Self` allows unwinding or if `Replacement` does not allow unwinding.
+pub trait UnwindCompatible<Replacement: Abi>: Abi {}
+
+/// Helper macro to implement [`NoUnwind`] and [`UnwindCompatible`].
+macro_rules! impl_unwind_markers {
+    (unwind: $($unwind:ident),*; no_unwind: $($no_unwind:ident),* $(;)?) => {
+        $(
+            impl<R: Abi> UnwindCompatible<R> for $unwind {}
+        )*
+        $(
+            impl NoUnwind for $no_unwind {}
+            impl<R: NoUnwind> UnwindCompatible<R> for $no_unwind {}
+        )*
+    };
+}
+
+impl_unwind_markers!(
+    unwind: Rust, CUnwind, SystemUnwind, AapcsUnwind, CdeclUnwind, StdcallUnwind, FastcallUnwind,
+        ThiscallUnwind, VectorcallUnwind, SysV64Unwind, Win64Unwind;
+    no_unwind: C, System, Aapcs, Cdecl, Stdcall, Fastcall, Thiscall, Vectorcall, SysV64, Win64, EfiApi;
+);
+
+/// Helper trait mapping an abi marker to its variant with (`UNWIND = true`) or without
+/// (`UNWIND = false`) unwinding, e.g. [`C`] to [`CUnwind`] and back.
+///
+/// `Rust` only has an unwinding and `efiapi` only a non-unwinding variant.
+pub trait UnwindVariant<const UNWIND: bool>: Abi {
+    /// The abi marker of the requested variant.
+    type Abi: Abi;
+}
+
+/// Helper macro to implement [`UnwindVariant`] for pairs of non-unwinding and unwinding abis.
+macro_rules! impl_unwind_variants {
+    ($($no_unwind:ident <=> $unwind:ident),* $(,)?) => {
+        $(
+            impl UnwindVariant<false> for $no_unwind {
+                type Abi = $no_unwind;
+            }
+            impl UnwindVariant<true> for $no_unwind {
+                type Abi = $unwind;
+            }
+            impl UnwindVariant<false> for $unwind {
+                type Abi = $no_unwind;
+            }
+            impl UnwindVariant<true> for $unwind {
+                type Abi = $unwind;
+            }
+        )*
+    };
+}
+
+impl_unwind_variants!(
+    C <=> CUnwind,
+    System <=> SystemUnwind,
+    Aapcs <=> AapcsUnwind,
+    Cdecl <=> CdeclUnwind,
+    Stdcall <=> StdcallUnwind,
+    Fastcall <=> FastcallUnwind,
+    Thiscall <=> ThiscallUnwind,
+    Vectorcall <=> VectorcallUnwind,
+    SysV64 <=> SysV64Unwind,
+    Win64 <=> Win64Unwind,
+);
+impl UnwindVariant<true> for Rust {
+    type Abi = Rust;
+}
+impl UnwindVariant<false> for EfiApi {
+    type Abi = EfiApi;
+}
+
+/// Helper macro to generate [`marker_str_of`].
+macro_rules! define_marker_str_of {
+    ($($name:ident => $value:pat),* $(,)?) => {
+        /// Returns the name of the [`Abi`] marker type corresponding to the given abi (e.g. `"SysV64Unwind"`).
+        #[must_use]
+        pub const fn marker_str_of(abi: AbiValue) -> &'static str {
+            match abi {
+                $( $value => stringify!($name), )*
+            }
+        }
+    };
+}
+
+define_marker_str_of!(
+    Rust => AbiValue::Rust,
+    C => AbiValue::C { unwind: false },
+    CUnwind => AbiValue::C { unwind: true },
+    System => AbiValue::System { unwind: false },
+    SystemUnwind => AbiValue::System { unwind: true },
+    Aapcs => AbiValue::Aapcs { unwind: false },
+    AapcsUnwind => AbiValue::Aapcs { unwind: true },
+    Cdecl => AbiValue::Cdecl { unwind: false },
+    CdeclUnwind => AbiValue::Cdecl { unwind: true },
+    Stdcall => AbiValue::Stdcall { unwind: false },
+    StdcallUnwind => AbiValue::Stdcall { unwind: true },
+    Fastcall => AbiValue::Fastcall { unwind: false },
+    FastcallUnwind => AbiValue::Fastcall { unwind: true },
+    Thiscall => AbiValue::Thiscall { unwind: false },
+    ThiscallUnwind => AbiValue::Thiscall { unwind: true },
+    Vectorcall => AbiValue::Vectorcall { unwind: false },
+    VectorcallUnwind => AbiValue::Vectorcall { unwind: true },
+    SysV64 => AbiValue::SysV64 { unwind: false },
+    SysV64Unwind => AbiValue::SysV64 { unwind: true },
+    Win64 => AbiValue::Win64 { unwind: false },
+    Win64Unwind => AbiValue::Win64 { unwind: true },
+    EfiApi => AbiValue::EfiApi,
+);
+
 /// Macro to convert an abi string to the corresponding [`Abi`] marker type.
 #[macro_export]
 macro_rules! abi {
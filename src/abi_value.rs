@@ -1,10 +1,15 @@
 use core::{
+    ffi::CStr,
     fmt::{self, Debug, Display},
     str::FromStr,
 };
 
 /// The abi or calling convention of a function pointer.
+///
+/// This enum is `#[non_exhaustive]` as new abis (e.g. interrupt or wasm abis) may be added in
+/// minor releases. Matches on it outside of this crate need a wildcard arm.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
 // from https://github.com/rust-lang/rust/blob/4fa80a5e733e2202d7ca4c203c2fdfda41cfe7dc/compiler/rustc_abi/src/extern_abi.rs#L21
 pub enum AbiValue {
     /* universal */
@@ -100,8 +105,12 @@ impl AbiValue {
     /// the current OS/architecture, following Rust compiler rules.
     ///
     /// Returns [`None`] if this abi is not supported on the current target.
+    ///
+    /// This only resolves the abi itself, not how arguments are passed under it. For example, on
+    /// `aarch64` Apple targets variadic arguments of `extern "C"` functions are always passed on
+    /// the stack, but this is part of the `C` abi there and `has_c_varargs` does not change the result.
     #[must_use]
-    pub fn canonize(self, has_c_varargs: bool) -> Option<AbiValue> {
+    pub const fn canonize(self, has_c_varargs: bool) -> Option<AbiValue> {
         // from https://github.com/rust-lang/rust/blob/4fa80a5e733e2202d7ca4c203c2fdfda41cfe7dc/compiler/rustc_target/src/spec/abi_map.rs#L79
         let os_windows = cfg!(target_os = "windows");
         let os_vexos = cfg!(target_os = "vexos");
@@ -162,6 +171,93 @@ impl AbiValue {
 
         Some(out)
     }
+
+    /// Returns `true` if both abis are supported on the current target and [canonize](AbiValue::canonize)
+    /// to the same calling convention.
+    ///
+    /// Abis which are not supported on the current target never compare equal, not even to themselves.
+    #[must_use]
+    pub fn eq_canonical(self, other: AbiValue) -> bool {
+        match (self.canonize(false), other.canonize(false)) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// Returns the name rustc uses for this abi, e.g. in diagnostics and `-Zunpretty` output.
+    ///
+    /// As of rustc 1.95 this is identical to [`to_str`](AbiValue::to_str) for all abis supported by
+    /// this crate (rustc renders it quoted, as in `extern "C-unwind" fn()`), but it is kept separate
+    /// so tooling matching compiler output does not depend on the user-facing spelling.
+    #[must_use]
+    pub const fn to_rustc_name(self) -> &'static str {
+        // from https://github.com/rust-lang/rust/blob/4fa80a5e733e2202d7ca4c203c2fdfda41cfe7dc/compiler/rustc_abi/src/extern_abi.rs
+        self.to_str()
+    }
+
+    /// Parses an abi name as emitted by rustc (see [`to_rustc_name`](AbiValue::to_rustc_name)).
+    ///
+    /// Accepts both the bare name (`C-unwind`) and the quoted form used in diagnostics (`"C-unwind"`).
+    #[must_use]
+    pub fn from_rustc_name(name: &str) -> Option<AbiValue> {
+        let name = name
+            .strip_prefix('"')
+            .and_then(|name| name.strip_suffix('"'))
+            .unwrap_or(name);
+        name.parse().ok()
+    }
+
+    /// Returns a [`Display`] wrapper rendering this abi as the `extern` clause of a declaration
+    /// (e.g. `extern "C"`), in contrast to [`Display`] for [`AbiValue`] which renders the bare token.
+    ///
+    /// [`AbiValue::Rust`] renders as nothing unless [`ExternClause::explicit`] is used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use fn_ptr::AbiValue;
+    /// assert_eq!(AbiValue::C { unwind: true }.to_extern_clause().to_string(), r#"extern "C-unwind""#);
+    /// assert_eq!(AbiValue::Rust.to_extern_clause().to_string(), "");
+    /// assert_eq!(AbiValue::Rust.to_extern_clause().explicit().to_string(), r#"extern "Rust""#);
+    /// ```
+    #[must_use]
+    pub const fn to_extern_clause(self) -> ExternClause {
+        ExternClause {
+            abi: self,
+            explicit: false,
+        }
+    }
+}
+
+/// The `extern` clause of an [`AbiValue`], returned by [`AbiValue::to_extern_clause`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExternClause {
+    abi: AbiValue,
+    explicit: bool,
+}
+
+impl ExternClause {
+    /// Always render the clause, including `extern "Rust"` for [`AbiValue::Rust`].
+    #[must_use]
+    pub const fn explicit(mut self) -> Self {
+        self.explicit = true;
+        self
+    }
+
+    /// Returns the abi of this clause.
+    #[must_use]
+    pub const fn abi(&self) -> AbiValue {
+        self.abi
+    }
+}
+
+impl Display for ExternClause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.abi == AbiValue::Rust && !self.explicit {
+            return Ok(());
+        }
+        write!(f, "extern \"{}\"", self.abi.to_str())
+    }
 }
 
 impl Display for AbiValue {
@@ -179,6 +275,33 @@ macro_rules! abi_kind_impl {
         }
     ) => {
         impl $t {
+            /// All abis in the order of their [`index`](Self::index).
+            const ALL: &'static [Self] = &[ $( Self::$variant $( { unwind: $uw } )?, )* ];
+
+            /// Returns a stable numeric index identifying this abi.
+            #[must_use]
+            #[allow(clippy::cast_possible_truncation)]
+            pub const fn index(&self) -> u8 {
+                let mut i = 0;
+                while i < Self::ALL.len() {
+                    if konst::eq_str(Self::ALL[i].to_str(), self.to_str()) {
+                        return i as u8;
+                    }
+                    i += 1;
+                }
+                unreachable!()
+            }
+
+            /// Returns the abi with the given [`index`](Self::index), if any.
+            #[must_use]
+            pub const fn from_index(index: u8) -> Option<Self> {
+                if (index as usize) < Self::ALL.len() {
+                    Some(Self::ALL[index as usize])
+                } else {
+                    None
+                }
+            }
+
             /// Returns the string representation of this abi.
             #[must_use]
             pub const fn to_str(&self) -> &'static str {
@@ -197,6 +320,31 @@ macro_rules! abi_kind_impl {
                 )*
                 None
             }
+
+            /// Returns the string representation of this abi as a nul-terminated C string.
+            #[must_use]
+            pub const fn as_cstr(&self) -> &'static CStr {
+                match self {
+                    $( Self::$variant $( { unwind: $uw } )? => const {
+                        match CStr::from_bytes_with_nul(concat!($tok, "\0").as_bytes()) {
+                            Ok(s) => s,
+                            Err(_) => unreachable!(),
+                        }
+                    }, )*
+                }
+            }
+
+            /// Parses an abi from a C string without requiring it to be valid UTF-8.
+            #[must_use]
+            pub const fn from_cstr(conv: &CStr) -> Option<Self> {
+                let bytes = conv.to_bytes();
+                $(
+                    if konst::slice::eq_bytes(bytes, $tok.as_bytes()) {
+                        return Some(Self::$variant $( { unwind: $uw } )?);
+                    }
+                )*
+                None
+            }
         }
 
         impl FromStr for $t {
@@ -212,6 +360,37 @@ macro_rules! abi_kind_impl {
     };
 }
 
+/// Error returned when converting an [`AbiValue`] into an [`Abi`](crate::abi::Abi) marker type
+/// representing a different abi.
+///
+/// ```rust
+/// # use fn_ptr::{AbiMismatch, AbiValue, abi};
+/// let err = abi::C::try_from(AbiValue::Rust).unwrap_err();
+/// assert_eq!(err, AbiMismatch { expected: AbiValue::C { unwind: false }, found: AbiValue::Rust });
+/// assert_eq!(err.to_string(), r#"expected abi "C", found "Rust""#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AbiMismatch {
+    /// The abi of the marker type.
+    pub expected: AbiValue,
+    /// The abi that was converted.
+    pub found: AbiValue,
+}
+
+impl fmt::Display for AbiMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected abi \"{}\", found \"{}\"",
+            self.expected, self.found
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AbiMismatch {}
+
+// NOTE: The order determines `AbiValue::index`, new abis have to be appended at the end.
 abi_kind_impl!(AbiValue => {
     Rust => "Rust",
     C { unwind: false } => "C",
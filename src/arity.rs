@@ -1,8 +1,25 @@
-/// Type-level marker trait for function arity, from [`A0`] to [`A12`].
+/// Type-level marker trait for function arity, from [`A0`] to [`A16`].
 pub trait Arity {
     /// Number of parameters for this arity.
     const N: usize;
 }
+/// Type-level representation of the number `N`, used to map it to its [`Arity`] marker via [`HasArityMarker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstArity<const N: usize>;
+
+/// Maps a [`ConstArity`] to the corresponding [`Arity`] marker type.
+///
+/// Implemented for [`ConstArity<0>`] to [`ConstArity<16>`].
+pub trait HasArityMarker {
+    /// The arity marker type.
+    type Marker: Arity;
+}
+
+/// The [`Arity`] marker type for `N` parameters, e.g. [`A2`] for `MarkerFor<2>`.
+///
+/// This is the canonical mapping used by [`arity!`](crate::arity!) and [`Tuple::Arity`](crate::Tuple::Arity).
+pub type MarkerFor<const N: usize> = <ConstArity<N> as HasArityMarker>::Marker;
+
 macro_rules! define_arity_marker {
     ($(($name:ident, $n:expr)),+ $(,)?) => {
         $(
@@ -15,7 +32,21 @@ macro_rules! define_arity_marker {
             impl Arity for $name {
                 const N: usize = $n;
             }
+
+            impl HasArityMarker for ConstArity<$n> {
+                type Marker = $name;
+            }
         )+
+
+        /// Returns the name of the [`Arity`] marker type for the given number of parameters
+        /// (e.g. `"A3"`), or [`None`] if there is no such marker.
+        #[must_use]
+        pub const fn arity_name(n: usize) -> Option<&'static str> {
+            match n {
+                $($n => Some(stringify!($name)),)+
+                _ => None,
+            }
+        }
     };
 }
 define_arity_marker!(
@@ -32,48 +63,34 @@ define_arity_marker!(
     (A10, 10),
     (A11, 11),
     (A12, 12),
+    (A13, 13),
+    (A14, 14),
+    (A15, 15),
+    (A16, 16),
 );
 
 /// Macro to convert an integral number to the corresponding [`Arity`] marker type.
+///
+/// Accepts any constant expression and expands to [`MarkerFor`].
 #[macro_export]
 macro_rules! arity {
-    (0) => {
-        $crate::marker::A0
-    };
-    (1) => {
-        $crate::marker::A1
-    };
-    (2) => {
-        $crate::marker::A2
-    };
-    (3) => {
-        $crate::marker::A3
+    ($n:expr) => {
+        $crate::arity::MarkerFor<{ $n }>
     };
-    (4) => {
-        $crate::marker::A4
-    };
-    (5) => {
-        $crate::marker::A5
-    };
-    (6) => {
-        $crate::marker::A6
-    };
-    (7) => {
-        $crate::marker::A7
-    };
-    (8) => {
-        $crate::marker::A8
-    };
-    (9) => {
-        $crate::marker::A9
-    };
-    (10) => {
-        $crate::marker::A10
-    };
-    (11) => {
-        $crate::marker::A11
-    };
-    (12) => {
-        $crate::marker::A12
+}
+
+/// Macro to get the [`Arity`] marker type of a function pointer type.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{arity, arity_marker_of};
+/// type F = extern "C" fn(i32, u8, u16);
+/// # static_assertions::assert_type_eq_all!(arity_marker_of!(F), arity!(3));
+/// ```
+#[macro_export]
+macro_rules! arity_marker_of {
+    ($ty:ty) => {
+        <<$ty as $crate::FnPtr>::Args as $crate::Tuple>::Arity
     };
 }
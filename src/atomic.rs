@@ -0,0 +1,107 @@
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+use crate::{FnPtr, OpaqueFn};
+
+/// A function pointer of type `F` which can be safely shared between threads.
+///
+/// This is backed by an [`AtomicPtr`], but all operations take and return typed function pointers.
+///
+/// # Example
+///
+/// ```rust
+/// # use core::sync::atomic::Ordering;
+/// # use fn_ptr::AtomicFnPtr;
+/// fn original(x: i32) -> i32 { x }
+/// fn hook(x: i32) -> i32 { x + 1 }
+///
+/// let slot = AtomicFnPtr::new(original as fn(i32) -> i32);
+/// let previous = slot.compare_exchange(original, hook, Ordering::AcqRel, Ordering::Acquire);
+/// assert_eq!(previous, Ok(original as fn(i32) -> i32));
+/// assert_eq!(slot.load(Ordering::Acquire)(1), 2);
+/// ```
+#[repr(transparent)]
+pub struct AtomicFnPtr<F: FnPtr> {
+    ptr: AtomicPtr<OpaqueFn>,
+    _marker: PhantomData<F>,
+}
+
+impl<F: FnPtr> AtomicFnPtr<F> {
+    /// Creates a new atomic function pointer.
+    #[must_use]
+    pub fn new(f: F) -> Self {
+        Self {
+            ptr: AtomicPtr::new(f.as_ptr().cast_mut()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Consumes the atomic and returns the contained function pointer.
+    #[must_use]
+    pub fn into_inner(self) -> F {
+        unsafe { F::from_ptr(self.ptr.into_inner()) }
+    }
+
+    /// Loads the function pointer.
+    ///
+    /// See [`AtomicPtr::load`].
+    #[must_use]
+    pub fn load(&self, order: Ordering) -> F {
+        unsafe { F::from_ptr(self.ptr.load(order)) }
+    }
+
+    /// Stores a function pointer.
+    ///
+    /// See [`AtomicPtr::store`].
+    pub fn store(&self, f: F, order: Ordering) {
+        self.ptr.store(f.as_ptr().cast_mut(), order);
+    }
+
+    /// Stores a function pointer, returning the previous one.
+    ///
+    /// See [`AtomicPtr::swap`].
+    #[must_use]
+    pub fn swap(&self, f: F, order: Ordering) -> F {
+        unsafe { F::from_ptr(self.ptr.swap(f.as_ptr().cast_mut(), order)) }
+    }
+
+    /// Stores `new` if the current function pointer is the same as `current`.
+    ///
+    /// Returns the previous function pointer, wrapped in [`Ok`] if the exchange happened and [`Err`] otherwise.
+    /// See [`AtomicPtr::compare_exchange`].
+    ///
+    /// # Errors
+    /// Returns the current function pointer if it did not match `current`.
+    pub fn compare_exchange(
+        &self,
+        current: F,
+        new: F,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<F, F> {
+        self.ptr
+            .compare_exchange(
+                current.as_ptr().cast_mut(),
+                new.as_ptr().cast_mut(),
+                success,
+                failure,
+            )
+            .map(|ptr| unsafe { F::from_ptr(ptr) })
+            .map_err(|ptr| unsafe { F::from_ptr(ptr) })
+    }
+}
+
+impl<F: FnPtr> From<F> for AtomicFnPtr<F> {
+    fn from(f: F) -> Self {
+        Self::new(f)
+    }
+}
+
+impl<F: FnPtr> Debug for AtomicFnPtr<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
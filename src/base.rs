@@ -1,15 +1,16 @@
 use core::{
     fmt::{Debug, Pointer},
-    hash::Hash,
+    hash::{Hash, Hasher},
     panic::{RefUnwindSafe, UnwindSafe},
 };
 
 use crate::{
-    WithAbi, WithAbiImpl, WithArgs, WithArgsImpl, WithOutput, WithOutputImpl, WithSafety,
-    WithSafetyImpl, abi,
+    BoundFirst, FirstArg, Signature, SplitFirst, WithAbi, WithAbiImpl, WithArgs, WithArgsImpl,
+    WithOutput, WithOutputImpl, WithSafety, WithSafetyImpl, abi,
     abi_value::AbiValue,
+    fnv::Fnv1a,
     safety::{self, Safe, Unsafe},
-    tuple::Tuple,
+    tuple::{CloneFromRefs, Tuple},
 };
 
 ffi_opaque::opaque! {
@@ -20,10 +21,19 @@ ffi_opaque::opaque! {
 /// Type alias for a raw untyped function pointer.
 pub type UntypedFnPtr = *const OpaqueFn;
 
+pub(crate) mod sealed {
+    /// Private supertrait of [`FnPtr`](crate::FnPtr) which prevents implementations outside of this crate.
+    pub trait Sealed {}
+}
+
 cfg_tt::cfg_tt! {
 /// Marker trait for all function pointers.
+///
+/// This trait is sealed and only implemented for function pointer types, so the unsafe
+/// casts and conversions can rely on every implementor being an actual function pointer.
 pub trait FnPtr:
-    PartialEq
+    sealed::Sealed
+    + PartialEq
     + Eq
     + PartialOrd
     + Ord
@@ -78,6 +88,18 @@ pub trait FnPtr:
     /// Marker type denoting abi
     type Abi: abi::Abi;
 
+    /// This function pointer type but safe, short for `<Self as WithSafety<Safe>>::F` (see [`make_safe!`](crate::make_safe)).
+    type Safe: FnPtr<Args = Self::Args, Output = Self::Output, Safety = Safe, Abi = Self::Abi>;
+
+    /// This function pointer type but unsafe, short for `<Self as WithSafety<Unsafe>>::F` (see [`make_unsafe!`](crate::make_unsafe)).
+    type Unsafe: FnPtr<Args = Self::Args, Output = Self::Output, Safety = Unsafe, Abi = Self::Abi>;
+
+    /// This function pointer type but with the `Rust` abi, short for `<Self as WithAbi<abi::Rust>>::F`.
+    type WithRust: FnPtr<Args = Self::Args, Output = Self::Output, Safety = Self::Safety, Abi = abi::Rust>;
+
+    /// This function pointer type but with the `C` abi, short for `<Self as WithAbi<abi::C>>::F`.
+    type WithC: FnPtr<Args = Self::Args, Output = Self::Output, Safety = Self::Safety, Abi = abi::C>;
+
     /// The function's arity (number of arguments).
     const ARITY: usize;
 
@@ -90,11 +112,113 @@ pub trait FnPtr:
     /// The abi associated with this function pointer.
     const ABI: AbiValue;
 
+    /// Whether the abi allows unwinding out of the function (e.g. `"Rust"` and `"C-unwind"`, but not `"C"`).
+    const IS_UNWIND: bool = Self::ABI.allows_unwind();
+
+    /// The sum of the sizes of all argument types.
+    ///
+    /// This does not account for any padding or register assignment of the calling convention.
+    const ARGS_SIZE: usize;
+
+    /// The number of bytes the arguments occupy on the stack under the 32-bit x86 calling conventions,
+    /// i.e. the sum of the sizes of all argument types, each rounded up to a multiple of 4.
+    ///
+    /// This is the size used in the `stdcall` and `fastcall` symbol decorations (e.g. `_Sleep@4`).
+    const X86_STACK_ARGS_SIZE: usize;
+
+    /// A compact encoding of [`ARITY`](FnPtr::ARITY), [`ABI`](FnPtr::ABI) and [`IS_SAFE`](FnPtr::IS_SAFE).
+    ///
+    /// The layout is stable and can be decoded using [`from_discriminant`](crate::from_discriminant):
+    /// - bits 0-7: the arity
+    /// - bits 8-15: the [`index`](AbiValue::index) of the abi
+    /// - bit 16: set if the function pointer is safe
+    /// - bits 17-31: always zero
+    #[allow(clippy::cast_possible_truncation)]
+    const DISCRIMINANT: u32 =
+        Self::ARITY as u32 | (Self::ABI.index() as u32) << 8 | (Self::IS_SAFE as u32) << 16;
+
+    /// Returns the [`Signature`] of this function pointer type.
+    #[must_use]
+    fn signature() -> Signature {
+        Signature::of::<Self>()
+    }
+
+    /// Returns the [`type_name`](core::any::type_name)s of the argument types, e.g. for printing the
+    /// resolved signature of a loaded function.
+    ///
+    /// Like [`type_name`](core::any::type_name) itself, the names are meant for diagnostics only and
+    /// their exact format may change between compiler versions.
+    ///
+    /// ```rust
+    /// # use fn_ptr::FnPtr;
+    /// type F = extern "C" fn(i32, *const u8) -> bool;
+    /// assert_eq!(F::arg_type_names(), ["i32", "*const u8"]);
+    /// assert_eq!(F::output_type_name(), "bool");
+    /// ```
+    #[must_use]
+    fn arg_type_names() -> ArgArray<Self, &'static str>;
+
+    /// Returns the [`type_name`](core::any::type_name) of the return type.
+    #[must_use]
+    fn output_type_name() -> &'static str {
+        core::any::type_name::<Self::Output>()
+    }
+
+    /// Writes the full signature of this function pointer type to `w`, e.g.
+    /// `unsafe extern "C" fn(i32, u8) -> bool`.
+    ///
+    /// The argument and return types are rendered using their [`type_name`](core::any::type_name)s
+    /// (see [`arg_type_names`](FnPtr::arg_type_names)).
+    ///
+    /// ```rust
+    /// # use fn_ptr::FnPtr;
+    /// let mut s = String::new();
+    /// <unsafe extern "C" fn(i32, u8) -> bool>::write_signature(&mut s).unwrap();
+    /// assert_eq!(s, r#"unsafe extern "C" fn(i32, u8) -> bool"#);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if writing to `w` fails.
+    fn write_signature(w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        if !Self::IS_SAFE {
+            w.write_str("unsafe ")?;
+        }
+        if Self::IS_EXTERN {
+            write!(w, "{} ", Self::ABI.to_extern_clause())?;
+        }
+        w.write_str("fn(")?;
+        for (i, name) in Self::arg_type_names().into_iter().enumerate() {
+            if i != 0 {
+                w.write_str(", ")?;
+            }
+            w.write_str(name)?;
+        }
+        w.write_char(')')?;
+        let output = Self::output_type_name();
+        if output != "()" {
+            write!(w, " -> {output}")?;
+        }
+        Ok(())
+    }
+
     /// Returns the address of this function.
     #[must_use]
     fn addr(&self) -> usize {
         self.as_ptr() as usize
     }
+    /// Returns the address of this function as a [`NonZeroUsize`](core::num::NonZeroUsize), e.g. for
+    /// storage in niche-optimized structures like `Option<NonZeroUsize>`.
+    ///
+    /// Function pointers are never null, so this relies only on the pointer having been constructed
+    /// soundly (i.e. not from a null address via [`from_addr`](FnPtr::from_addr)). This is checked
+    /// with a debug assertion.
+    #[must_use]
+    fn nonzero_addr(&self) -> core::num::NonZeroUsize {
+        let addr = self.addr();
+        debug_assert_ne!(addr, 0, "function pointer is null");
+        // SAFETY: valid function pointers are never null.
+        unsafe { core::num::NonZeroUsize::new_unchecked(addr) }
+    }
     /// Constructs an instance from an address.
     ///
     /// # Safety
@@ -103,6 +227,164 @@ pub trait FnPtr:
     unsafe fn from_addr(addr: usize) -> Self {
         unsafe { Self::from_ptr(addr as UntypedFnPtr) }
     }
+    /// Constructs an instance from an address, returning [`None`] if the address is null.
+    ///
+    /// # Safety
+    /// If the address is not null, it has to point to a function of the correct type.
+    #[must_use]
+    unsafe fn try_from_addr(addr: usize) -> Option<Self> {
+        if addr == 0 {
+            None
+        } else {
+            Some(unsafe { Self::from_addr(addr) })
+        }
+    }
+    /// Returns the offset of this function relative to the given module base address.
+    ///
+    /// Returns [`None`] if the function lies below `module_base`.
+    #[must_use]
+    fn rva_from(&self, module_base: usize) -> Option<usize> {
+        self.addr().checked_sub(module_base)
+    }
+    /// Constructs an instance from an offset relative to a module base address.
+    ///
+    /// Returns [`None`] if the resulting address overflows or is null.
+    ///
+    /// # Safety
+    /// If the resulting address is not null, it has to point to a function of the correct type.
+    #[must_use]
+    unsafe fn from_rva(module_base: usize, rva: usize) -> Option<Self> {
+        let addr = module_base.checked_add(rva)?;
+        unsafe { Self::try_from_addr(addr) }
+    }
+    /// Returns `true` if this function pointer points to the same address as `known`, in the same
+    /// way as [`core::ptr::fn_addr_eq`] but for function pointers of any type.
+    ///
+    /// This is intended for short-circuiting known callbacks (e.g. a default handler). As with
+    /// `fn_addr_eq`, the same function may have different addresses in different codegen units and
+    /// different functions may be merged to the same address, so a result of `false` does not
+    /// guarantee that the functions are distinct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use fn_ptr::FnPtr;
+    /// fn default_handler(_: i32) {}
+    ///
+    /// let callback: fn(i32) = default_handler;
+    /// if callback.is(default_handler as fn(i32)) {
+    ///     // fast path
+    /// }
+    /// ```
+    #[must_use]
+    fn is<G: FnPtr>(&self, known: G) -> bool {
+        self.addr() == FnPtr::addr(&known)
+    }
+    /// Returns `true` if both function pointers point to the same function and their abis
+    /// resolve to the same calling convention on the current target.
+    ///
+    /// See [`AbiValue::eq_canonical`] for how abis are compared.
+    #[must_use]
+    fn eq_ignoring_abi<G: FnPtr>(&self, other: &G) -> bool {
+        self.addr() == other.addr() && Self::ABI.eq_canonical(G::ABI)
+    }
+    /// Returns the address of the first instruction of this function.
+    ///
+    /// On `arm` this clears the thumb bit (see [`is_thumb`](FnPtr::is_thumb)), on all other
+    /// architectures this is the same as [`addr`](FnPtr::addr).
+    #[must_use]
+    fn code_address(&self) -> usize {
+        if cfg!(target_arch = "arm") {
+            self.addr() & !1
+        } else {
+            self.addr()
+        }
+    }
+    /// Returns `true` if this function pointer points to thumb code.
+    ///
+    /// Always `false` on architectures other than `arm`.
+    #[must_use]
+    fn is_thumb(&self) -> bool {
+        cfg!(target_arch = "arm") && self.addr() & 1 != 0
+    }
+    /// Returns a pointer to the machine code of this function (see [`code_address`](FnPtr::code_address)).
+    ///
+    /// The pointer is created with exposed provenance and is only valid for reading the instruction
+    /// stream on targets where code pages are readable.
+    #[must_use]
+    fn code_bytes_ptr(&self) -> *const u8 {
+        core::ptr::with_exposed_provenance(self.code_address())
+    }
+    /// Copies the first `N` bytes of the machine code of this function.
+    ///
+    /// # Safety
+    /// The first `N` bytes of the function have to be readable (see [`code_bytes_ptr`](FnPtr::code_bytes_ptr)).
+    #[must_use]
+    unsafe fn read_prologue<const N: usize>(&self) -> [u8; N] {
+        unsafe { self.code_bytes_ptr().cast::<[u8; N]>().read_unaligned() }
+    }
+    /// Returns a 64-bit FNV-1a hash of the first `N` bytes of the machine code of this function.
+    ///
+    /// Together with [`prologue_unchanged`](FnPtr::prologue_unchanged) this can be used to detect
+    /// whether the start of a function was patched (e.g. by a hook). This is not a cryptographic hash.
+    ///
+    /// # Safety
+    /// Same as [`read_prologue`](FnPtr::read_prologue).
+    #[must_use]
+    unsafe fn prologue_hash<const N: usize>(&self) -> u64 {
+        let bytes = unsafe { self.read_prologue::<N>() };
+        let mut hasher = Fnv1a::new();
+        hasher.write(&bytes);
+        hasher.finish()
+    }
+    /// Returns `true` if the [`prologue_hash`](FnPtr::prologue_hash) of the first `N` bytes equals `expected`.
+    ///
+    /// # Safety
+    /// Same as [`read_prologue`](FnPtr::read_prologue).
+    #[must_use]
+    unsafe fn prologue_unchanged<const N: usize>(&self, expected: u64) -> bool {
+        unsafe { self.prologue_hash::<N>() == expected }
+    }
+    /// Returns the path of the loaded module (executable or shared library) containing this function.
+    ///
+    /// Uses `dladdr` on unix and `GetModuleHandleExW`/`GetModuleFileNameW` on windows.
+    /// Returns [`None`] if the address does not belong to a loaded module or the platform is not supported.
+    ///
+    /// Only available with the `std` feature.
+    #[cfg(feature = "std")](
+    #[must_use]
+    fn module_path(&self) -> Option<std::path::PathBuf> {
+        crate::module::module_path_of(self.as_ptr().cast())
+    }
+    )
+    /// Checks the declared [`ARITY`](FnPtr::ARITY) against the DWARF debug info of the module image
+    /// `module` (e.g. the contents of the executable or shared library file) loaded at `module_base`.
+    ///
+    /// `module_base` is the runtime address of the start of the loaded image, e.g. the module handle
+    /// on windows or `dli_fbase` from `dladdr` on unix. For ELF images this is where the lowest
+    /// `PT_LOAD` segment is mapped, which for position independent images equals the load bias.
+    ///
+    /// The function is looked up by its [`rva_from`](FnPtr::rva_from) the base, translated to the
+    /// link time address used in the debug info, and the number of recorded parameters is compared
+    /// against the arity. Types of the parameters are not checked.
+    ///
+    /// Only available with the `debuginfo` feature.
+    ///
+    /// # Errors
+    /// Returns a [`SignatureMismatch`] if the arities differ or the function could not be found
+    /// in the debug info.
+    #[cfg(feature = "debuginfo")](
+    fn verify_against_debuginfo(
+        &self,
+        module: &[u8],
+        module_base: usize,
+    ) -> Result<(), crate::SignatureMismatch> {
+        let rva = self
+            .rva_from(module_base)
+            .ok_or(crate::SignatureMismatch::NotFound)?;
+        crate::debuginfo::verify_arity(module, rva as u64, Self::ARITY)
+    }
+    )
     /// Returns a untyped function pointer for this function.
     #[must_use]
     fn as_ptr(&self) -> UntypedFnPtr;
@@ -113,16 +395,202 @@ pub trait FnPtr:
     #[must_use]
     #[allow(clippy::missing_safety_doc)] // false positive?
     unsafe fn from_ptr(ptr: UntypedFnPtr) -> Self;
+    /// Constructs an instance from an untyped function pointer, returning [`None`] if the pointer is null.
+    ///
+    /// # Safety
+    /// If the pointer is not null, it has to point to a function of the correct type.
+    #[must_use]
+    unsafe fn try_from_ptr(ptr: UntypedFnPtr) -> Option<Self> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Self::from_ptr(ptr) })
+        }
+    }
+    /// Constructs an instance from an untyped function pointer, returning [`None`] if the
+    /// pointer is null or misaligned for the instruction set of the current target.
+    ///
+    /// This is a best-effort sanity check for pointers read from untrusted tables. The alignment
+    /// requirement is target-specific (e.g. 4 bytes on `aarch64`, 2 bytes for thumb code on `arm`)
+    /// and on targets with variable-length instructions like `x86` only null is rejected.
+    ///
+    /// # Safety
+    /// Same as [`from_ptr`](FnPtr::from_ptr) if the checks pass.
+    #[must_use]
+    unsafe fn from_ptr_checked_aligned(ptr: UntypedFnPtr) -> Option<Self> {
+        let addr = ptr.addr();
+        if addr == 0 {
+            return None;
+        }
+        let (code_addr, align) = if cfg!(target_arch = "arm") {
+            // bit 0 selects thumb mode with 2-byte instructions, arm mode requires 4 bytes.
+            if addr & 1 != 0 { (addr & !1, 2) } else { (addr, 4) }
+        } else if cfg!(any(
+            target_arch = "aarch64",
+            target_arch = "powerpc",
+            target_arch = "powerpc64",
+            target_arch = "loongarch64",
+            target_arch = "mips",
+            target_arch = "mips64",
+            target_arch = "sparc64"
+        )) {
+            (addr, 4)
+        } else if cfg!(any(target_arch = "riscv32", target_arch = "riscv64")) {
+            // compressed instructions are 2 bytes.
+            (addr, 2)
+        } else {
+            (addr, 1)
+        };
+        if code_addr % align != 0 {
+            return None;
+        }
+        Some(unsafe { Self::from_ptr(ptr) })
+    }
+
+    /// Reads a function pointer of this type stored at the given byte offset within `value`, e.g. an
+    /// arm of a `#[repr(C)]` union or a field of a `#[repr(C)]` struct with a known layout.
+    ///
+    /// The read is unaligned and the pointer is constructed with the provenance of the stored bytes,
+    /// so they have to be initialized with an actual function pointer (not an integer).
+    ///
+    /// # Safety
+    /// `offset + size_of::<Self>()` has to be within `size_of::<U>()` (checked in debug builds) and the
+    /// bytes at `offset` have to be a valid function pointer of this type.
+    #[must_use]
+    unsafe fn from_union_field<U>(value: &U, offset: usize) -> Self {
+        debug_assert!(
+            offset
+                .checked_add(core::mem::size_of::<Self>())
+                .is_some_and(|end| end <= core::mem::size_of::<U>()),
+            "offset out of bounds"
+        );
+        unsafe {
+            core::ptr::from_ref(value)
+                .cast::<u8>()
+                .add(offset)
+                .cast::<Self>()
+                .read_unaligned()
+        }
+    }
 
     /// Casts this function pointer to a different function pointer type.
     ///
+    /// The address is always preserved, so casting to any function pointer type and back (e.g.
+    /// `f.cast::<G>().cast::<Self>()`) results in a pointer equal to `f`. The same holds for all other
+    /// conversions like [`with_abi`](FnPtr::with_abi) or [`as_unsafe`](FnPtr::as_unsafe), which are
+    /// implemented in terms of `cast`.
+    ///
     /// # Safety
     /// Caller must ensure that the resulting transformation is sound.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use fn_ptr::FnPtr;
+    /// let f: fn(i32) -> i32 = |x| x + 1;
+    /// let g: unsafe extern "C" fn(u64) = unsafe { f.cast() };
+    /// let back: fn(i32) -> i32 = unsafe { g.cast() };
+    /// assert_eq!(g.addr(), f.addr());
+    /// assert!(back == f);
+    /// ```
     #[must_use]
     unsafe fn cast<F: FnPtr>(&self) -> F {
         unsafe { FnPtr::from_ptr(self.as_ptr()) }
     }
 
+    /// Casts this function pointer to a function pointer type `G` which only differs in arguments that
+    /// are [`#[repr(transparent)]` wrappers](crate::TransparentArg) over the original argument types.
+    ///
+    /// Unlike [`cast`](FnPtr::cast) this is safe, as the callee receives values of the types it expects.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use fn_ptr::{FnPtr, transparent_arg};
+    /// transparent_arg! {
+    ///     /// A handle passed through a C callback.
+    ///     #[derive(Clone, Copy)]
+    ///     pub struct Handle(i32);
+    /// }
+    ///
+    /// extern "C" fn close(handle: i32, flags: u32) -> i32 { handle + flags as i32 }
+    ///
+    /// let f = close as extern "C" fn(i32, u32) -> i32;
+    /// let g: extern "C" fn(Handle, u32) -> i32 = f.cast_arg_transparent();
+    /// assert_eq!(g(Handle(3), 1), 4);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use fn_ptr::FnPtr;
+    /// struct NotTransparent(i32);
+    ///
+    /// extern "C" fn close(handle: i32) {}
+    /// let f = close as extern "C" fn(i32);
+    /// let g: extern "C" fn(NotTransparent) = f.cast_arg_transparent(); // error
+    /// ```
+    #[must_use]
+    fn cast_arg_transparent<G>(&self) -> G
+    where
+        G: FnPtr<Output = Self::Output, Safety = Self::Safety, Abi = Self::Abi>,
+        Self::Args: crate::TransparentArgs<G::Args>,
+    {
+        // SAFETY: the arguments of `G` are abi compatible with those of `Self` and can be passed where
+        // the original arguments are expected.
+        unsafe { self.cast() }
+    }
+
+    /// Casts this function pointer to a function pointer type `F` with the [same signature](crate::SameSignature).
+    ///
+    /// Unlike [`cast`](FnPtr::cast) this is safe, as the arguments, output, safety and abi of both types
+    /// are proven equal at compile time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use fn_ptr::FnPtr;
+    /// type Callback = extern "C" fn(i32) -> i32;
+    ///
+    /// extern "C" fn inc(x: i32) -> i32 { x + 1 }
+    ///
+    /// let f = inc as extern "C" fn(i32) -> i32;
+    /// let g: Callback = f.cast_same();
+    /// assert_eq!(g(1), 2);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use fn_ptr::FnPtr;
+    /// extern "C" fn inc(x: i32) -> i32 { x + 1 }
+    /// let f = inc as extern "C" fn(i32) -> i32;
+    /// let g: extern "C" fn(u32) -> i32 = f.cast_same(); // error
+    /// ```
+    #[must_use]
+    fn cast_same<F: FnPtr>(&self) -> F
+    where
+        Self: crate::SameSignature<F>,
+    {
+        // SAFETY: `Self` and `F` have identical arguments, output, safety and abi.
+        unsafe { self.cast() }
+    }
+
+    /// Casts this function pointer to a different [static](StaticFnPtr) function pointer type `G` if the
+    /// total argument size ([`ARGS_SIZE`](FnPtr::ARGS_SIZE)) and the return type size of both types match.
+    ///
+    /// Returns [`None`] otherwise.
+    ///
+    /// # Safety
+    /// Caller must ensure that the resulting transformation is sound.
+    /// Matching sizes do not imply compatible calling conventions or argument types.
+    #[must_use]
+    unsafe fn cast_size_checked<G: StaticFnPtr>(&self) -> Option<G> {
+        if Self::ARGS_SIZE == G::ARGS_SIZE
+            && core::mem::size_of::<Self::Output>() == core::mem::size_of::<G::Output>()
+        {
+            Some(unsafe { self.cast() })
+        } else {
+            None
+        }
+    }
+
     /// Produces an unsafe version of this function pointer.
     #[must_use]
     fn as_unsafe(&self) -> <Self as WithSafety<Unsafe>>::F {
@@ -188,6 +656,12 @@ pub trait FnPtr:
 }
 }
 
+/// A tuple of references to the argument types of `F`, e.g. `(&'a A, &'a B)` for `fn(A, B)`.
+pub type ArgsRef<'a, F> = <<F as FnPtr>::Args as CloneFromRefs>::Refs<'a>;
+
+/// An array with one `T` per argument of `F`, e.g. `[T; 2]` for `fn(A, B)`.
+pub type ArgArray<F, T> = <<F as FnPtr>::Args as Tuple>::Array<T>;
+
 /// Marker trait for all callable *safe* function pointer types (`fn` / `extern fn`).
 pub trait SafeFnPtr: FnPtr<Safety = Safe> {
     /// Invokes the function pointed to with the given args.
@@ -204,8 +678,132 @@ pub trait SafeFnPtr: FnPtr<Safety = Safe> {
     /// ```
     // NOTE: Can't use "call" due to fn_traits feature
     fn invoke(&self, args: Self::Args) -> Self::Output;
+
+    /// Invokes the function pointed to with clones of the referenced args.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fn_ptr::SafeFnPtr;
+    /// fn len(s: String, extra: usize) -> usize { s.len() + extra }
+    ///
+    /// let f: fn(String, usize) -> usize = len;
+    /// let s = String::from("abc");
+    /// assert_eq!(f.invoke_refs((&s, &1)), 4);
+    /// ```
+    fn invoke_refs(&self, args: ArgsRef<'_, Self>) -> Self::Output
+    where
+        Self::Args: CloneFromRefs,
+    {
+        self.invoke(Self::Args::clone_from_refs(args))
+    }
+
+    /// Invokes the function pointed to with the given args while holding `guard`, which is
+    /// dropped after the call returns (or during unwinding if it panics).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fn_ptr::SafeFnPtr;
+    /// # use std::sync::Mutex;
+    /// static LOCK: Mutex<()> = Mutex::new(());
+    ///
+    /// let f: fn(i32) -> i32 = |x| x + 1;
+    /// assert_eq!(f.invoke_guarded((1,), LOCK.lock().unwrap()), 2);
+    /// assert!(LOCK.try_lock().is_ok());
+    /// ```
+    fn invoke_guarded<G>(&self, args: Self::Args, guard: G) -> Self::Output {
+        let output = self.invoke(args);
+        drop(guard);
+        output
+    }
+
+    /// Converts this function pointer to `G`, which may only differ in safety.
+    ///
+    /// As this function pointer is safe, using it as a safe or unsafe function is always sound.
+    /// See [`UnsafeFnPtr::reinterpret_safety`] for the reverse direction.
+    fn reinterpret_safety<G>(&self) -> G
+    where
+        G: FnPtr<Args = Self::Args, Output = Self::Output, Abi = Self::Abi>,
+    {
+        unsafe { self.cast() }
+    }
+
+    /// Invokes the function pointed to with the given args, passing the pointer, args and result
+    /// through [`black_box`](core::hint::black_box) so the call is not optimized away in benchmarks.
+    fn invoke_black_box(&self, args: Self::Args) -> Self::Output {
+        let f = core::hint::black_box(*self);
+        core::hint::black_box(f.invoke(core::hint::black_box(args)))
+    }
+
+    /// Binds the first argument of this function pointer to `first` (see [`BoundFirst`]).
+    fn bind_first(self, first: FirstArg<Self>) -> BoundFirst<Self, FirstArg<Self>>
+    where
+        Self::Args: SplitFirst,
+    {
+        BoundFirst::new(self, first)
+    }
+
+    /// Returns a closure invoking this function pointer with an argument tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fn_ptr::SafeFnPtr;
+    /// fn visit(out: &mut dyn FnMut((i32,)) -> i32) -> i32 { out((2,)) + out((3,)) }
+    ///
+    /// let f: fn(i32) -> i32 = |x| x * 2;
+    /// assert_eq!(visit(&mut f.as_fn_mut()), 10);
+    /// ```
+    fn as_fn_mut(&self) -> impl FnMut(Self::Args) -> Self::Output + '_ {
+        move |args| self.invoke(args)
+    }
+
+    /// Invokes the function pointed to with the given args if called on the `expected` thread.
+    ///
+    /// Only available with the `std` feature.
+    ///
+    /// # Errors
+    /// Returns [`WrongThread`] without invoking the function if the current thread is not `expected`.
+    #[cfg(feature = "std")]
+    fn invoke_checked_thread(
+        &self,
+        args: Self::Args,
+        expected: std::thread::ThreadId,
+    ) -> Result<Self::Output, WrongThread> {
+        let actual = std::thread::current().id();
+        if actual == expected {
+            Ok(self.invoke(args))
+        } else {
+            Err(WrongThread { expected, actual })
+        }
+    }
 }
 
+/// Error returned by [`SafeFnPtr::invoke_checked_thread`] when called on an unexpected thread.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WrongThread {
+    /// The thread the function was expected to be called on.
+    pub expected: std::thread::ThreadId,
+    /// The thread the function was actually called on.
+    pub actual: std::thread::ThreadId,
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for WrongThread {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "function invoked on thread {:?} instead of {:?}",
+            self.actual, self.expected
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WrongThread {}
+
 /// Marker trait for all callable *unsafe* function pointer types (`unsafe fn` / `unsafe extern fn`).
 pub trait UnsafeFnPtr: FnPtr<Safety = Unsafe> {
     /// Invokes the function pointed to with the given args.
@@ -226,13 +824,160 @@ pub trait UnsafeFnPtr: FnPtr<Safety = Unsafe> {
     /// ```
     // NOTE: Can't use "call" due to fn_traits feature
     unsafe fn invoke(&self, args: Self::Args) -> Self::Output;
+
+    /// Invokes the function pointed to with the given args while holding `guard`, which is
+    /// dropped after the call returns (or during unwinding if it panics).
+    ///
+    /// # Safety
+    /// Same as [`UnsafeFnPtr::invoke`].
+    unsafe fn invoke_guarded<G>(&self, args: Self::Args, guard: G) -> Self::Output {
+        let output = unsafe { self.invoke(args) };
+        drop(guard);
+        output
+    }
+
+    /// Binds the first argument of this function pointer to `first` (see [`BoundFirst`]).
+    fn bind_first(self, first: FirstArg<Self>) -> BoundFirst<Self, FirstArg<Self>>
+    where
+        Self::Args: SplitFirst,
+    {
+        BoundFirst::new(self, first)
+    }
+
+    /// Converts this function pointer to `G`, which may only differ in safety.
+    ///
+    /// # Safety
+    /// If `G` is safe, the function must be sound to call with any arguments of its signature.
+    unsafe fn reinterpret_safety<G>(&self) -> G
+    where
+        G: FnPtr<Args = Self::Args, Output = Self::Output, Abi = Self::Abi>,
+    {
+        unsafe { self.cast() }
+    }
 }
 
 /// Marker trait for all *static* function pointer types.
 /// The return type and all parameter types have to be `'static`.
-pub trait StaticFnPtr: FnPtr + 'static {}
+pub trait StaticFnPtr: FnPtr + 'static {
+    /// Returns the [`TypeId`](core::any::TypeId) of the argument tuple ([`FnPtr::Args`]).
+    #[must_use]
+    fn args_type_id() -> core::any::TypeId
+    where
+        Self::Args: 'static,
+    {
+        core::any::TypeId::of::<Self::Args>()
+    }
+
+    /// Returns the [`TypeId`](core::any::TypeId)s of the individual argument types, e.g. to check at
+    /// runtime that two function pointer types agree on their arguments before casting between them.
+    ///
+    /// Like all [`TypeId`](core::any::TypeId)s, this requires all argument types to be `'static`.
+    ///
+    /// ```rust
+    /// # use fn_ptr::StaticFnPtr;
+    /// type Host = extern "C" fn(u32, *const u8) -> i32;
+    /// type Plugin = unsafe extern "C" fn(u32, *const u8) -> i32;
+    /// assert_eq!(Host::arg_type_ids(), Plugin::arg_type_ids());
+    /// assert_eq!(Host::output_type_id(), Plugin::output_type_id());
+    /// ```
+    #[must_use]
+    fn arg_type_ids() -> ArgArray<Self, core::any::TypeId>
+    where
+        Self::Args: 'static,
+    {
+        Self::Args::type_ids()
+    }
+
+    /// Returns the [`TypeId`](core::any::TypeId) of the return type ([`FnPtr::Output`]).
+    #[must_use]
+    fn output_type_id() -> core::any::TypeId
+    where
+        Self::Output: 'static,
+    {
+        core::any::TypeId::of::<Self::Output>()
+    }
+
+    /// Returns a deterministic string encoding of this function pointer type's signature, modelled
+    /// after the Itanium C++ ABI (e.g. `FviE` for `extern "C" fn(i32)`).
+    ///
+    /// The encoding is not a valid C++ mangling, but it is deterministic and readable, making it usable
+    /// as a key for the shape of a function, e.g. in handshakes between processes.
+    ///
+    /// Types other than primitives, pointers and references are encoded from their
+    /// [`type_name`](core::any::type_name), whose format may change between compiler versions.
+    /// Tokens are therefore only comparable between binaries built by the same compiler.
+    ///
+    /// Safety and abis other than `"C"` are encoded as vendor qualifiers in front, so
+    /// `unsafe extern "system" fn(*const u8) -> bool` is encoded as `U6unsafeU6systemFbPKhE`.
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use fn_ptr::StaticFnPtr;
+    /// extern "C" fn callback(_: i32) {}
+    /// assert_eq!((callback as extern "C" fn(i32)).signature_mangled(), "FviE");
+    /// assert_eq!(((|| 0u64) as fn() -> u64).signature_mangled(), "U4RustFyvE");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn signature_mangled(&self) -> alloc::string::String {
+        crate::mangle::mangle::<Self>()
+    }
+}
 impl<F: FnPtr + 'static> StaticFnPtr for F {}
 
+/// Marker trait for all function pointer types returning a function pointer, e.g.
+/// `extern "C" fn(i32, extern "C" fn(i32)) -> extern "C" fn(i32)` as returned by `signal`.
+///
+/// The bound on the output is implied, so generic code can use the returned function pointer directly.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{FnPtr, ReturnsFnPtr};
+/// fn returned_arity<F: ReturnsFnPtr>() -> usize {
+///     <F::Output as FnPtr>::ARITY
+/// }
+///
+/// type Signal = extern "C" fn(i32, extern "C" fn(i32)) -> extern "C" fn(i32);
+/// assert_eq!(returned_arity::<Signal>(), 1);
+/// ```
+pub trait ReturnsFnPtr: FnPtr<Output: FnPtr> {}
+impl<F: FnPtr<Output: FnPtr>> ReturnsFnPtr for F {}
+
+/// Calls the function at the given address as the given function pointer type.
+///
+/// Expands to constructing the function pointer via [`FnPtr::from_addr`] and invoking it with the given
+/// arguments. The address and arguments are evaluated outside of the `unsafe` block.
+/// A justification why the call is sound has to be given with `SAFETY = "..."`.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{FnPtr, call_addr};
+/// extern "C" fn add(a: i32, b: i32) -> i32 { a + b }
+/// let addr = (add as extern "C" fn(i32, i32) -> i32).addr();
+///
+/// let result = call_addr!(
+///     SAFETY = "`addr` is the address of `add`",
+///     addr => extern "C" fn(i32, i32) -> i32,
+///     (2, 3)
+/// );
+/// assert_eq!(result, 5);
+/// ```
+#[macro_export]
+macro_rules! call_addr {
+    (SAFETY = $reason:literal, $addr:expr => $ty:ty, ($($arg:expr),* $(,)?)) => {{
+        let _: &::core::primitive::str = $reason;
+        let addr: ::core::primitive::usize = $addr;
+        let args = ($($arg,)*);
+        let f: $ty = unsafe { <$ty as $crate::FnPtr>::from_addr(addr) };
+        unsafe { $crate::UnsafeFnPtr::invoke(&$crate::FnPtr::as_unsafe(&f), args) }
+    }};
+}
+
 #[cfg(test)]
 #[allow(unused)]
 mod test {
@@ -0,0 +1,62 @@
+use crate::{FnPtr, SafeFnPtr, SplitFirst, UnsafeFnPtr};
+
+/// Shorthand for the arguments of `F` without the first one.
+pub type RestArgs<F> = <<F as FnPtr>::Args as SplitFirst>::Rest;
+
+/// Shorthand for the type of the first argument of `F`.
+pub type FirstArg<F> = <<F as FnPtr>::Args as SplitFirst>::First;
+
+/// A function pointer with its first argument bound to a value, returned by [`SafeFnPtr::bind_first`]
+/// and [`UnsafeFnPtr::bind_first`].
+///
+/// This models the conventional leading context parameter of callbacks.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::SafeFnPtr;
+/// fn scale(factor: i32, x: i32) -> i32 { factor * x }
+///
+/// let double = (scale as fn(i32, i32) -> i32).bind_first(2);
+/// assert_eq!(double.invoke((21,)), 42);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundFirst<F, First> {
+    /// The bound function pointer.
+    pub f: F,
+    /// The value of the first argument.
+    pub first: First,
+}
+
+impl<F: FnPtr, First> BoundFirst<F, First>
+where
+    F::Args: SplitFirst<First = First>,
+{
+    /// Binds `first` as the first argument of `f`.
+    pub const fn new(f: F, first: First) -> Self {
+        Self { f, first }
+    }
+}
+
+impl<F: SafeFnPtr, First: Clone> BoundFirst<F, First>
+where
+    F::Args: SplitFirst<First = First>,
+{
+    /// Invokes the function with a clone of the bound first argument followed by `rest`.
+    pub fn invoke(&self, rest: RestArgs<F>) -> F::Output {
+        self.f.invoke(F::Args::join_first(self.first.clone(), rest))
+    }
+}
+
+impl<F: UnsafeFnPtr, First: Clone> BoundFirst<F, First>
+where
+    F::Args: SplitFirst<First = First>,
+{
+    /// Invokes the function with a clone of the bound first argument followed by `rest`.
+    ///
+    /// # Safety
+    /// Same as [`UnsafeFnPtr::invoke`].
+    pub unsafe fn invoke_unsafe(&self, rest: RestArgs<F>) -> F::Output {
+        unsafe { self.f.invoke(F::Args::join_first(self.first.clone(), rest)) }
+    }
+}
@@ -0,0 +1,119 @@
+use core::ffi::c_void;
+
+use crate::{FnPtr, UntypedFnPtr};
+
+/// Registers a callback together with a userdata pointer with a C-style api.
+///
+/// Erases `callback` to an [`UntypedFnPtr`], casts `userdata` to `*mut c_void` and passes both
+/// to `api_register`, returning its result.
+///
+/// # Lifetime of `userdata`
+/// The pointer passed to `api_register` is only guaranteed to be valid for the duration of this call.
+/// If the api stores it and invokes the callback later, the caller has to ensure that `userdata`
+/// outlives (and is not moved or accessed mutably elsewhere during) every such invocation,
+/// e.g. by unregistering the callback before `userdata` is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// # use core::ffi::c_void;
+/// # use fn_ptr::{FnPtr, UntypedFnPtr, register_callback};
+/// extern "C" fn on_event(userdata: *mut c_void) {
+///     unsafe { *userdata.cast::<u32>() += 1 };
+/// }
+///
+/// // stand-in for an `extern "C"` registration function
+/// fn register(callback: UntypedFnPtr, userdata: *mut c_void) -> i32 {
+///     let callback: extern "C" fn(*mut c_void) = unsafe { FnPtr::from_ptr(callback) };
+///     callback(userdata);
+///     0
+/// }
+///
+/// let mut count = 0u32;
+/// let result = register_callback(register, on_event as extern "C" fn(*mut c_void), &mut count);
+/// assert_eq!(result, 0);
+/// assert_eq!(count, 1);
+/// ```
+pub fn register_callback<Ctx, F: FnPtr, R>(
+    api_register: impl FnOnce(UntypedFnPtr, *mut c_void) -> R,
+    callback: F,
+    userdata: &mut Ctx,
+) -> R {
+    api_register(callback.as_ptr(), core::ptr::from_mut(userdata).cast())
+}
+
+/// Declare a `#[repr(C)]` table of nullable callbacks, as taken by many C apis.
+///
+/// Each field of type `F` is stored as `Option<F>`, which has the same layout as a nullable C
+/// function pointer. The generated struct gets a `const EMPTY` with all fields set to [`None`], `const`
+/// builder methods `with_<field>` and implements [`Default`].
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::callback_table;
+/// callback_table! {
+///     /// Callbacks of my c library.
+///     pub struct MyOps {
+///         pub init: extern "C" fn(),
+///         pub tick: extern "C" fn(f64),
+///     }
+/// }
+///
+/// extern "C" fn tick(_dt: f64) {}
+///
+/// const OPS: MyOps = MyOps::EMPTY.with_tick(tick);
+/// assert!(OPS.init.is_none());
+/// assert!(OPS.tick.is_some());
+/// ```
+#[macro_export]
+macro_rules! callback_table {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident : $ty:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Clone, Copy)]
+        $vis struct $name {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: ::core::option::Option<$ty>,
+            )*
+        }
+
+        #[allow(dead_code)]
+        impl $name {
+            /// A table with all callbacks set to `None`.
+            pub const EMPTY: Self = Self {
+                $($field: ::core::option::Option::None,)*
+            };
+
+            $crate::__private::paste! {
+                $(
+                    #[doc = "Sets the `" $field "` callback."]
+                    #[must_use]
+                    pub const fn [<with_ $field>](mut self, callback: $ty) -> Self {
+                        const _: () = {
+                            const fn assert_fn_ptr<F: $crate::FnPtr>() {}
+                            assert_fn_ptr::<$ty>();
+                        };
+                        self.$field = ::core::option::Option::Some(callback);
+                        self
+                    }
+                )*
+            }
+        }
+
+        impl ::core::default::Default for $name {
+            fn default() -> Self {
+                Self::EMPTY
+            }
+        }
+    };
+}
@@ -0,0 +1,89 @@
+use crate::{FnPtr, SafeFnPtr, UnsafeFnPtr};
+
+/// Number of `usize` words in the guard buffer of [`Canaried`].
+const GUARD_WORDS: usize = 32;
+/// The pattern written into the guard buffer of [`Canaried`].
+const CANARY: usize = usize::from_ne_bytes([0xA5; size_of::<usize>()]);
+
+/// Debugging wrapper around a function pointer which checks a stack canary around each call.
+///
+/// With `debug_assertions` enabled, [`invoke`](Canaried::invoke) fills a guard buffer on the stack
+/// of the caller with a known pattern, calls the function and panics if the pattern was changed,
+/// which indicates that the callee wrote past its frame, e.g. because it expects more or larger
+/// arguments ([`FnPtr::ARGS_SIZE`]) than declared. Without `debug_assertions` the function is
+/// invoked directly.
+///
+/// Only available with the `std` feature.
+///
+/// # Limitations
+///
+/// This is a best-effort check which only detects writes that actually reach the guard buffer in the
+/// frame of the caller. The placement of the buffer is up to the compiler and it usually lies above
+/// the outgoing argument area, so a callee with the wrong arity which only reads or writes its
+/// argument slots and registers will often go unnoticed. Only overruns large enough to reach the
+/// buffer are reported, and corruption of the return address or callee-saved registers usually
+/// crashes before the canary is checked. The check can not recover from the corruption, it only
+/// turns silent memory corruption into a descriptive panic.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::Canaried;
+/// extern "C" fn add(a: i32, b: i32) -> i32 { a + b }
+///
+/// let add = Canaried(add as extern "C" fn(i32, i32) -> i32);
+/// assert_eq!(add.invoke((1, 2)), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Canaried<F: FnPtr>(pub F);
+
+impl<F: SafeFnPtr> Canaried<F> {
+    /// Invokes the wrapped function, checking the stack canary afterwards with `debug_assertions`.
+    ///
+    /// # Panics
+    /// Panics if the canary was overwritten by the call.
+    #[inline(never)]
+    pub fn invoke(&self, args: F::Args) -> F::Output {
+        with_canary::<F, _>(FnPtr::addr(&self.0), || self.0.invoke(args))
+    }
+}
+
+impl<F: UnsafeFnPtr> Canaried<F> {
+    /// Invokes the wrapped function, checking the stack canary afterwards with `debug_assertions`.
+    ///
+    /// # Safety
+    /// Same as [`UnsafeFnPtr::invoke`].
+    ///
+    /// # Panics
+    /// Panics if the canary was overwritten by the call.
+    #[inline(never)]
+    pub unsafe fn invoke_unsafe(&self, args: F::Args) -> F::Output {
+        with_canary::<F, _>(FnPtr::addr(&self.0), || unsafe { self.0.invoke(args) })
+    }
+}
+
+#[inline]
+fn with_canary<F: FnPtr, R>(addr: usize, call: impl FnOnce() -> R) -> R {
+    if !cfg!(debug_assertions) {
+        return call();
+    }
+
+    let mut guard = [CANARY; GUARD_WORDS];
+    // make sure the guard buffer is materialized on the stack.
+    let guard = core::hint::black_box(&mut guard);
+    let output = call();
+    let intact = guard
+        .iter()
+        // SAFETY: `word` is a reference into the live guard buffer. The volatile read makes
+        // sure the value written by a misbehaving callee is observed.
+        .all(|word| unsafe { core::ptr::read_volatile(word) } == CANARY);
+    assert!(
+        intact,
+        "stack canary overwritten by call to {addr:#x} declared as `{}` (args size {} bytes); \
+         the function likely has a different signature or abi",
+        core::any::type_name::<F>(),
+        F::ARGS_SIZE,
+    );
+    output
+}
@@ -1,7 +1,9 @@
+use core::marker::PhantomData;
+
 use crate::{
-    FnPtr, abi,
+    BuildFn, FnPtr, SafeFnPtr, abi,
     safety::{self, Safe, Unsafe},
-    tuple::Tuple,
+    tuple::{Concat, Element, Flatten, ReplaceElement, Reverse, SplitLast, Tuple},
 };
 
 /// Helper trait used by [`WithAbi`] (use it instead).
@@ -34,6 +36,33 @@ impl<Abi: abi::Abi, F: FnPtr + WithAbiImpl<Abi, Self>> WithAbi<Abi> for F {
     type F = <Self as WithAbiImpl<Abi, Self>>::F;
 }
 
+/// Helper trait to toggle whether the abi of a function pointer type allows unwinding, while preserving
+/// its safety, arguments, return type and calling convention (e.g. `"C"` <-> `"C-unwind"`).
+///
+/// This is used by [`with_unwind!`](crate::with_unwind) under the hood. Abis without the requested
+/// variant (`Rust` without unwinding, `efiapi` with unwinding) do not implement this trait.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::WithUnwind;
+/// type F = unsafe extern "system" fn(i32) -> i32;
+/// type G = <F as WithUnwind<true>>::F;
+/// // `G` is `unsafe extern "system-unwind" fn(i32) -> i32`
+/// # static_assertions::assert_type_eq_all!(G, unsafe extern "system-unwind" fn(i32) -> i32);
+/// ```
+pub trait WithUnwind<const UNWIND: bool>: FnPtr {
+    /// The function pointer type with the requested unwind variant of its abi (preserving all other properties).
+    type F: FnPtr<Args = Self::Args, Output = Self::Output, Safety = Self::Safety>;
+}
+impl<const UNWIND: bool, F> WithUnwind<UNWIND> for F
+where
+    F: FnPtr<Abi: abi::UnwindVariant<UNWIND>>
+        + WithAbi<<<F as FnPtr>::Abi as abi::UnwindVariant<UNWIND>>::Abi>,
+{
+    type F = <F as WithAbi<<<F as FnPtr>::Abi as abi::UnwindVariant<UNWIND>>::Abi>>::F;
+}
+
 /// Helper trait used by [`WithSafety`] (use it instead).
 /// This trait is required to allow [`FnPtr`] to be a subtrait of [`WithSafety`], which eliminates
 /// the need for a type bound when using a fixed safety marker.
@@ -115,6 +144,9 @@ pub trait WithOutputImpl<F: FnPtr = Self> {
 ///
 /// This is used by [`with_output!`](crate::with_output) under the hood.
 ///
+/// As [`WithOutputImpl`] is a supertrait of [`FnPtr`], `F: WithOutput<T>` holds for any `F: FnPtr`
+/// and any `T` without additional bounds.
+///
 /// # Example
 ///
 /// ```rust
@@ -145,6 +177,9 @@ pub trait WithArgsImpl<F: FnPtr = Self> {
 ///
 /// This is used by [`with_args!`](crate::with_args) under the hood.
 ///
+/// Argument tuples may contain references, but their lifetimes have to be nameable (e.g. `(&'a u8,)`).
+/// Higher-ranked function pointer types like `for<'a> fn(&'a u8)` do not implement [`FnPtr`].
+///
 /// # Example
 ///
 /// ```rust
@@ -162,6 +197,421 @@ impl<Args: Tuple, F: FnPtr + WithArgsImpl<Self>> WithArgs<Args> for F {
     type F = <Self as WithArgsImpl<Self>>::F<Args>;
 }
 
+/// Helper trait to flatten nested argument tuples of a function pointer type by one level while
+/// preserving its safety, abi and return type.
+///
+/// Every argument has to be a tuple, whose elements become the arguments of the resulting type
+/// (see [`Flatten`] for the exact rules). This is used by [`flatten_args!`](crate::flatten_args) under the hood.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::FlattenArgs;
+/// type F = fn((i32, u8), (f32,)) -> i32;
+/// type G = <F as FlattenArgs>::F;
+/// // `G` is `fn(i32, u8, f32) -> i32`
+/// # static_assertions::assert_type_eq_all!(G, fn(i32, u8, f32) -> i32);
+/// ```
+pub trait FlattenArgs: FnPtr {
+    /// The function pointer type with the flattened arguments (preserving all other properties).
+    type F: FnPtr<Output = Self::Output, Safety = Self::Safety, Abi = Self::Abi>;
+}
+impl<F: FnPtr> FlattenArgs for F
+where
+    F::Args: Flatten<Output: BuildFn<F::Safety, F::Abi, F::Output>>,
+{
+    type F = <<F::Args as Flatten>::Output as BuildFn<F::Safety, F::Abi, F::Output>>::F;
+}
+
+/// Helper trait to replace the argument type at index `N` of a function pointer type with `T` while
+/// preserving its abi, safety, return type and all other arguments.
+///
+/// This is used by [`with_arg!`](crate::with_arg) under the hood. Indices outside of the arguments do
+/// not satisfy the trait bounds and fail to compile.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::WithArg;
+/// type F = extern "C" fn(i32, u8, i32) -> i32;
+/// type G = <F as WithArg<1, u16>>::F;
+/// // `G` is `extern "C" fn(i32, u16, i32) -> i32`
+/// # static_assertions::assert_type_eq_all!(G, extern "C" fn(i32, u16, i32) -> i32);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use fn_ptr::WithArg;
+/// fn widened() -> Option<<fn(i32, u8) as WithArg<2, u16>>::F> { None } // error: index 2 is out of range
+/// ```
+pub trait WithArg<const N: usize, T>: FnPtr {
+    /// The function pointer type with the replaced argument (preserving all other properties).
+    type F: FnPtr<Output = Self::Output, Safety = Self::Safety, Abi = Self::Abi>;
+}
+impl<F: FnPtr, const N: usize, T> WithArg<N, T> for F
+where
+    F::Args: ReplaceElement<N, T, Output: BuildFn<F::Safety, F::Abi, F::Output>>,
+{
+    type F =
+        <<F::Args as ReplaceElement<N, T>>::Output as BuildFn<F::Safety, F::Abi, F::Output>>::F;
+}
+
+/// Helper trait to add a leading argument of type `T` to a function pointer type while preserving its
+/// abi, safety and return type.
+///
+/// This is used by [`prepend_arg!`](crate::prepend_arg) under the hood. It is not implemented if the
+/// resulting arity would exceed the maximum arity.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::PrependArg;
+/// type F = extern "C" fn(i32) -> i32;
+/// type G = <F as PrependArg<*mut u8>>::F;
+/// // `G` is `extern "C" fn(*mut u8, i32) -> i32`
+/// # static_assertions::assert_type_eq_all!(G, extern "C" fn(*mut u8, i32) -> i32);
+/// ```
+pub trait PrependArg<T>: FnPtr {
+    /// The function pointer type with the additional argument (preserving all other properties).
+    type F: FnPtr<Output = Self::Output, Safety = Self::Safety, Abi = Self::Abi>;
+}
+impl<F: FnPtr, T> PrependArg<T> for F
+where
+    (T,): Concat<F::Args, Output: BuildFn<F::Safety, F::Abi, F::Output>>,
+{
+    type F = <<(T,) as Concat<F::Args>>::Output as BuildFn<F::Safety, F::Abi, F::Output>>::F;
+}
+
+/// Helper trait to add a trailing argument of type `T` to a function pointer type while preserving its
+/// abi, safety and return type.
+///
+/// This is used by [`append_arg!`](crate::append_arg) under the hood. It is not implemented if the
+/// resulting arity would exceed the maximum arity.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::AppendArg;
+/// type F = extern "C" fn(i32) -> i32;
+/// type G = <F as AppendArg<*mut u8>>::F;
+/// // `G` is `extern "C" fn(i32, *mut u8) -> i32`
+/// # static_assertions::assert_type_eq_all!(G, extern "C" fn(i32, *mut u8) -> i32);
+/// ```
+pub trait AppendArg<T>: FnPtr {
+    /// The function pointer type with the additional argument (preserving all other properties).
+    type F: FnPtr<Output = Self::Output, Safety = Self::Safety, Abi = Self::Abi>;
+}
+impl<F: FnPtr, T> AppendArg<T> for F
+where
+    F::Args: Concat<(T,), Output: BuildFn<F::Safety, F::Abi, F::Output>>,
+{
+    type F = <<F::Args as Concat<(T,)>>::Output as BuildFn<F::Safety, F::Abi, F::Output>>::F;
+}
+
+/// Helper trait to remove the last argument of a function pointer type while preserving its abi, safety
+/// and return type.
+///
+/// This is used by [`pop_arg!`](crate::pop_arg) under the hood. It is not implemented for function
+/// pointers without arguments.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::PopArg;
+/// type F = extern "C" fn(i32, u8) -> i32;
+/// type G = <F as PopArg>::F;
+/// // `G` is `extern "C" fn(i32) -> i32`
+/// # static_assertions::assert_type_eq_all!(G, extern "C" fn(i32) -> i32);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use fn_ptr::PopArg;
+/// fn popped() -> Option<<fn() as PopArg>::F> { None } // error: no argument to remove
+/// ```
+pub trait PopArg: FnPtr {
+    /// The type of the removed argument.
+    type Popped;
+    /// The function pointer type without the last argument (preserving all other properties).
+    type F: FnPtr<Output = Self::Output, Safety = Self::Safety, Abi = Self::Abi>;
+}
+impl<F: FnPtr> PopArg for F
+where
+    F::Args: SplitLast<Init: BuildFn<F::Safety, F::Abi, F::Output>>,
+{
+    type Popped = <F::Args as SplitLast>::Last;
+    type F = <<F::Args as SplitLast>::Init as BuildFn<F::Safety, F::Abi, F::Output>>::F;
+}
+
+/// Helper trait to reverse the order of the arguments of a function pointer type while preserving its
+/// abi, safety and return type.
+///
+/// This is used by [`reverse_args!`](crate::reverse_args) under the hood. Like [`WithAbi`], this only
+/// changes the type; it does not change how the arguments are passed, so casting a function pointer
+/// to its reversed type does not reorder the arguments of its calls.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::ReverseArgs;
+/// type F = fn(i32, u8, bool);
+/// type G = <F as ReverseArgs>::F;
+/// // `G` is `fn(bool, u8, i32)`
+/// # static_assertions::assert_type_eq_all!(G, fn(bool, u8, i32));
+/// ```
+pub trait ReverseArgs: FnPtr {
+    /// The function pointer type with the reversed arguments (preserving all other properties).
+    type F: FnPtr<Output = Self::Output, Safety = Self::Safety, Abi = Self::Abi>;
+}
+impl<F: FnPtr> ReverseArgs for F
+where
+    F::Args: Reverse<Output: BuildFn<F::Safety, F::Abi, F::Output>>,
+{
+    type F = <<F::Args as Reverse>::Output as BuildFn<F::Safety, F::Abi, F::Output>>::F;
+}
+
+/// Computes a function pointer type with the argument types at the indices `I` and `J` swapped.
+///
+/// Only the argument types are swapped, the abi, safety and output are preserved. Swapping an
+/// index with itself is allowed and yields `Self`. Indices outside of the arguments do not
+/// satisfy the trait bounds and fail to compile.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::SwapArgs;
+/// type F = extern "C" fn(i32, u8, f32) -> bool;
+/// type G = <F as SwapArgs<0, 2>>::F;
+/// // `G` is `extern "C" fn(f32, u8, i32) -> bool`
+/// # static_assertions::assert_type_eq_all!(G, extern "C" fn(f32, u8, i32) -> bool);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use fn_ptr::SwapArgs;
+/// fn swapped() -> Option<<fn(i32, u8) as SwapArgs<0, 2>>::F> { None } // error: index 2 is out of range
+/// ```
+pub trait SwapArgs<const I: usize, const J: usize>: FnPtr {
+    /// The function pointer type with the swapped arguments (preserving all other properties).
+    type F: FnPtr<Output = Self::Output, Safety = Self::Safety, Abi = Self::Abi>;
+}
+impl<F: FnPtr, const I: usize, const J: usize> SwapArgs<I, J> for F
+where
+    F::Args: Element<I> + Element<J> + ReplaceElement<I, <F::Args as Element<J>>::Type>,
+    <F::Args as ReplaceElement<I, <F::Args as Element<J>>::Type>>::Output: ReplaceElement<
+            J,
+            <F::Args as Element<I>>::Type,
+            Output: BuildFn<F::Safety, F::Abi, F::Output>,
+        >,
+{
+    type F =
+        <<<F::Args as ReplaceElement<I, <F::Args as Element<J>>::Type>>::Output as ReplaceElement<
+            J,
+            <F::Args as Element<I>>::Type,
+        >>::Output as BuildFn<F::Safety, F::Abi, F::Output>>::F;
+}
+
+/// Helper trait to compose a safe function pointer with a unary safe function pointer `G` taking its output.
+///
+/// [`F`](ComposeFn::F) is the type of the composition, i.e. `Self` with the return type of `G`, and
+/// [`compose`](ComposeFn::compose) builds the composed function as a closure.
+/// `G` has to take exactly the output of `Self` as its only argument.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::ComposeFn;
+/// fn parse(s: &'static str) -> usize { s.len() }
+/// fn double(n: usize) -> u64 { n as u64 * 2 }
+///
+/// type F = <fn(&'static str) -> usize as ComposeFn<fn(usize) -> u64>>::F;
+/// // `F` is `fn(&'static str) -> u64`
+/// # static_assertions::assert_type_eq_all!(F, fn(&'static str) -> u64);
+///
+/// let f = (parse as fn(&'static str) -> usize).compose(double as fn(usize) -> u64);
+/// assert_eq!(f(("abc",)), 6);
+/// ```
+pub trait ComposeFn<G: SafeFnPtr<Args = (Self::Output,)>>: SafeFnPtr {
+    /// The function pointer type of the composition (preserving the arguments, abi and safety of `Self`).
+    type F: FnPtr<Args = Self::Args, Output = G::Output, Safety = Self::Safety, Abi = Self::Abi>;
+
+    /// Returns a closure calling `g` with the output of `self`.
+    fn compose(self, g: G) -> impl Fn(Self::Args) -> G::Output;
+}
+impl<F: SafeFnPtr, G: SafeFnPtr<Args = (F::Output,)>> ComposeFn<G> for F {
+    type F = <F as WithOutput<G::Output>>::F;
+
+    fn compose(self, g: G) -> impl Fn(Self::Args) -> G::Output {
+        move |args| g.invoke((self.invoke(args),))
+    }
+}
+
+/// Marker trait for function pointer types with the same arguments, output, safety and abi as `Other`.
+///
+/// Used as a bound by [`FnPtr::cast_same`] to prove at compile time that a cast is sound.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::SameSignature;
+/// fn assert_same<F: SameSignature<G>, G: fn_ptr::FnPtr>() {}
+///
+/// type Callback = extern "C" fn(i32) -> i32;
+/// assert_same::<Callback, extern "C" fn(i32) -> i32>();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use fn_ptr::SameSignature;
+/// fn assert_same<F: SameSignature<G>, G: fn_ptr::FnPtr>() {}
+///
+/// assert_same::<extern "C" fn(i32), extern "system" fn(u32)>(); // error
+/// ```
+pub trait SameSignature<Other: FnPtr>:
+    FnPtr<Args = Other::Args, Output = Other::Output, Safety = Other::Safety, Abi = Other::Abi>
+{
+}
+impl<F, Other> SameSignature<Other> for F
+where
+    Other: FnPtr,
+    F: FnPtr<Args = Other::Args, Output = Other::Output, Safety = Other::Safety, Abi = Other::Abi>,
+{
+}
+
+/// Marker trait for function pointer types with the same [arity](FnPtr::ARITY) as `Other`.
+///
+/// Unlike [`SameSignature`] the argument types themselves may differ.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::SameArity;
+/// fn assert_same_arity<F: SameArity<G>, G: fn_ptr::FnPtr>() {}
+///
+/// assert_same_arity::<fn(i32, u8), unsafe extern "C" fn(f64, *const u8) -> bool>();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use fn_ptr::SameArity;
+/// fn assert_same_arity<F: SameArity<G>, G: fn_ptr::FnPtr>() {}
+///
+/// assert_same_arity::<fn(i32), fn(i32, i32)>(); // error
+/// ```
+pub trait SameArity<Other: FnPtr>:
+    FnPtr<Args: Tuple<Arity = <Other::Args as Tuple>::Arity>>
+{
+}
+impl<F, Other> SameArity<Other> for F
+where
+    Other: FnPtr,
+    F: FnPtr<Args: Tuple<Arity = <Other::Args as Tuple>::Arity>>,
+{
+}
+
+/// Marker trait for function pointer types with the same [abi](FnPtr::Abi) as `Other`.
+///
+/// Unlike [`SameSignature`] the arguments, output and safety may differ.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::SameAbi;
+/// fn assert_same_abi<F: SameAbi<G>, G: fn_ptr::FnPtr>() {}
+///
+/// assert_same_abi::<extern "C" fn(i32), unsafe extern "C" fn() -> u8>();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use fn_ptr::SameAbi;
+/// fn assert_same_abi<F: SameAbi<G>, G: fn_ptr::FnPtr>() {}
+///
+/// assert_same_abi::<extern "C" fn(), extern "C-unwind" fn()>(); // error
+/// ```
+pub trait SameAbi<Other: FnPtr>: FnPtr<Abi = Other::Abi> {}
+impl<F: FnPtr<Abi = Other::Abi>, Other: FnPtr> SameAbi<Other> for F {}
+
+/// A function pointer type or its nullable form `Option<F>`, as accepted by the type rewriting macros
+/// (e.g. [`with_abi!`](crate::with_abi), [`make_unsafe!`](crate::make_unsafe)).
+///
+/// The macros rewrite the [`Inner`](MaybeNullable::Inner) function pointer type and [`Wrap`](MaybeNullable::Wrap)
+/// the result again, so the nullability is preserved.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{make_unsafe, with_abi};
+/// type Callback = Option<extern "C" fn(i32)>;
+/// type UnsafeCallback = make_unsafe!(Callback);
+/// // `UnsafeCallback` is `Option<unsafe extern "C" fn(i32)>`
+/// # static_assertions::assert_type_eq_all!(UnsafeCallback, Option<unsafe extern "C" fn(i32)>);
+/// # static_assertions::assert_type_eq_all!(with_abi!("system", Callback), Option<extern "system" fn(i32)>);
+/// ```
+pub trait MaybeNullable {
+    /// The function pointer type, i.e. `Self` or `F` for `Option<F>`.
+    type Inner: FnPtr;
+    /// Wraps the function pointer type `G` in the same way as `Self`, i.e. `G` or `Option<G>`.
+    type Wrap<G: FnPtr>;
+}
+impl<F: FnPtr> MaybeNullable for F {
+    type Inner = F;
+    type Wrap<G: FnPtr> = G;
+}
+impl<F: FnPtr> MaybeNullable for Option<F> {
+    type Inner = F;
+    type Wrap<G: FnPtr> = Option<G>;
+}
+
+/// Marker used with [`Reshape`] to leave a dimension of a function pointer type unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Keep;
+
+/// Marker used with [`Reshape`] to replace a dimension of a function pointer type with `T`.
+///
+/// Abi and safety markers as well as argument tuples can also be passed to [`Reshape`] directly,
+/// `Set` is mainly required to change the return type.
+pub struct Set<T>(PhantomData<T>);
+
+/// Helper trait resolving a [`Reshape`] parameter against the current value `T` of that dimension.
+pub trait ReshapeParam<T> {
+    /// The resolved type of the dimension.
+    type Out;
+}
+impl<T> ReshapeParam<T> for Keep {
+    type Out = T;
+}
+impl<T, U> ReshapeParam<T> for Set<U> {
+    type Out = U;
+}
+
+/// Helper trait to change multiple properties of a function pointer type at once.
+///
+/// Each parameter is either [`Keep`] to preserve the corresponding property, [`Set<T>`] to replace it, or for
+/// abi, safety and arguments the new marker type or argument tuple itself.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{Reshape, Keep, Set, abi, safety};
+/// type F = extern "C" fn(i32) -> i32;
+/// type G = <F as Reshape<abi::System, safety::Unsafe, Set<u64>>>::F;
+/// // `G` is `unsafe extern "system" fn(i32) -> u64`
+/// # static_assertions::assert_type_eq_all!(G, unsafe extern "system" fn(i32) -> u64);
+///
+/// type H = <F as Reshape<Keep, Keep, Keep, (u8, u16)>>::F;
+/// // `H` is `extern "C" fn(u8, u16) -> i32`
+/// # static_assertions::assert_type_eq_all!(H, extern "C" fn(u8, u16) -> i32);
+/// ```
+pub trait Reshape<Abi = Keep, Safety = Keep, Output = Keep, Args = Keep>: FnPtr {
+    /// The function pointer type with the requested properties.
+    type F: FnPtr;
+}
+impl<F, Abi, Safety, Output, Args> Reshape<Abi, Safety, Output, Args> for F
+where
+    F: FnPtr,
+    Abi: ReshapeParam<F::Abi, Out: abi::Abi>,
+    Safety: ReshapeParam<F::Safety, Out: safety::Safety>,
+    Output: ReshapeParam<F::Output>,
+    Args: ReshapeParam<F::Args, Out: BuildFn<Safety::Out, Abi::Out, Output::Out>>,
+{
+    type F = <Args::Out as BuildFn<Safety::Out, Abi::Out, Output::Out>>::F;
+}
+
 /// Construct a function-pointer type identical to the given one but using the specified abi.
 ///
 /// Accepts either:
@@ -183,11 +633,11 @@ impl<Args: Tuple, F: FnPtr + WithArgsImpl<Self>> WithArgs<Args> for F {
 #[macro_export]
 macro_rules! with_abi {
     ( $abi:path, $ty:ty ) => {
-        <$ty as $crate::WithAbi<$abi>>::F
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::WithAbi<$abi>>::F>
     };
 
     ( $lit:tt, $ty:ty ) => {
-        <$ty as $crate::WithAbi<$crate::abi!($lit)>>::F
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::WithAbi<$crate::abi!($lit)>>::F>
     };
 }
 
@@ -227,7 +677,7 @@ macro_rules! with_safety {
     };
 
     ( @inner $safety:ty, $ty:ty ) => {
-        <$ty as $crate::WithSafety<$safety>>::F
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::WithSafety<$safety>>::F>
     };
 }
 
@@ -245,7 +695,7 @@ macro_rules! with_safety {
 #[macro_export]
 macro_rules! make_safe {
     ( $ty:ty ) => {
-        <$ty as $crate::WithSafety<$crate::safety::Safe>>::F
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::WithSafety<$crate::safety::Safe>>::F>
     };
 }
 
@@ -263,7 +713,41 @@ macro_rules! make_safe {
 #[macro_export]
 macro_rules! make_unsafe {
     ( $ty:ty ) => {
-        <$ty as $crate::WithSafety<$crate::safety::Unsafe>>::F
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::WithSafety<$crate::safety::Unsafe>>::F>
+    };
+}
+
+/// Declare a function-pointer type alias together with its unsafe and nullable variants.
+///
+/// For each `Name = fn-type` this generates `Name`, `NameUnsafe` (see [`make_unsafe!`]) and
+/// `NamePtr` (`Option<Name>`, the nullable form with the same layout as a raw function pointer).
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::fn_types;
+/// # use core::ffi::c_void;
+/// fn_types! {
+///     pub Comparator = extern "C" fn(*const c_void, *const c_void) -> i32;
+///     Destructor = extern "C" fn(*mut c_void);
+/// }
+/// # static_assertions::assert_type_eq_all!(ComparatorUnsafe, unsafe extern "C" fn(*const c_void, *const c_void) -> i32);
+/// # static_assertions::assert_type_eq_all!(ComparatorPtr, Option<Comparator>);
+/// # static_assertions::assert_type_eq_all!(DestructorUnsafe, unsafe extern "C" fn(*mut c_void));
+/// ```
+#[macro_export]
+macro_rules! fn_types {
+    ( $( $(#[$attr:meta])* $vis:vis $name:ident = $ty:ty );+ $(;)? ) => {
+        $crate::__private::paste! {
+            $(
+                $(#[$attr])*
+                $vis type $name = $ty;
+                #[doc = "Unsafe variant of [`" $name "`]."]
+                $vis type [<$name Unsafe>] = $crate::make_unsafe!($name);
+                #[doc = "Nullable variant of [`" $name "`]."]
+                $vis type [<$name Ptr>] = ::core::option::Option<$name>;
+            )+
+        }
     };
 }
 
@@ -282,7 +766,7 @@ macro_rules! make_unsafe {
 #[macro_export]
 macro_rules! with_output {
     ( $out:ty, $ty:ty ) => {
-        <$ty as $crate::WithOutput<$out>>::F
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::WithOutput<$out>>::F>
     };
 }
 
@@ -301,6 +785,216 @@ macro_rules! with_output {
 #[macro_export]
 macro_rules! with_args {
     ( $args:ty, $ty:ty ) => {
-        <$ty as $crate::WithArgs<$args>>::F
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::WithArgs<$args>>::F>
+    };
+}
+
+/// Construct a function-pointer type identical to the given one but with its tuple arguments
+/// flattened by one level (see [`FlattenArgs`]).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fn_ptr::flatten_args;
+/// type F = unsafe fn((u8, u16), (), (u32,));
+/// type G = flatten_args!(F);
+/// // `G` is `unsafe fn(u8, u16, u32)`
+/// # static_assertions::assert_type_eq_all!(G, unsafe fn(u8, u16, u32));
+/// ```
+#[macro_export]
+macro_rules! flatten_args {
+    ( $ty:ty ) => {
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::FlattenArgs>::F>
+    };
+}
+
+/// Construct a function-pointer type identical to the given one but with the (non-)unwinding variant of
+/// its abi (see [`WithUnwind`]).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fn_ptr::with_unwind;
+/// type F = with_unwind!(true, extern "C" fn(i32));
+/// // `F` is `extern "C-unwind" fn(i32)`
+/// # static_assertions::assert_type_eq_all!(F, extern "C-unwind" fn(i32));
+///
+/// type G = with_unwind!(false, F);
+/// // `G` is `extern "C" fn(i32)`
+/// # static_assertions::assert_type_eq_all!(G, extern "C" fn(i32));
+/// ```
+#[macro_export]
+macro_rules! with_unwind {
+    ( $unwind:expr, $ty:ty ) => {
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::WithUnwind<{ $unwind }>>::F>
+    };
+}
+
+/// Construct a function-pointer type identical to the given one but with the argument type at the given
+/// index replaced (see [`WithArg`]).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fn_ptr::with_arg;
+/// type F = unsafe extern "system" fn(*mut u8, u32) -> i32;
+/// type G = with_arg!(1, u64, F);
+/// // `G` is `unsafe extern "system" fn(*mut u8, u64) -> i32`
+/// # static_assertions::assert_type_eq_all!(G, unsafe extern "system" fn(*mut u8, u64) -> i32);
+/// ```
+#[macro_export]
+macro_rules! with_arg {
+    ( $n:expr, $arg:ty, $ty:ty ) => {
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::WithArg<{ $n }, $arg>>::F>
     };
 }
+
+/// Construct a function-pointer type identical to the given one but with an additional leading argument
+/// (see [`PrependArg`]).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fn_ptr::prepend_arg;
+/// struct Ctx;
+/// type F = prepend_arg!(*mut Ctx, extern "C" fn(i32));
+/// // `F` is `extern "C" fn(*mut Ctx, i32)`
+/// # static_assertions::assert_type_eq_all!(F, extern "C" fn(*mut Ctx, i32));
+/// ```
+#[macro_export]
+macro_rules! prepend_arg {
+    ( $arg:ty, $ty:ty ) => {
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::PrependArg<$arg>>::F>
+    };
+}
+
+/// Construct a function-pointer type identical to the given one but with an additional trailing argument
+/// (see [`AppendArg`]).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fn_ptr::append_arg;
+/// type F = append_arg!(usize, unsafe fn(*const u8) -> bool);
+/// // `F` is `unsafe fn(*const u8, usize) -> bool`
+/// # static_assertions::assert_type_eq_all!(F, unsafe fn(*const u8, usize) -> bool);
+/// ```
+#[macro_export]
+macro_rules! append_arg {
+    ( $arg:ty, $ty:ty ) => {
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::AppendArg<$arg>>::F>
+    };
+}
+
+/// Construct a function-pointer type identical to the given one but without its last argument
+/// (see [`PopArg`]).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fn_ptr::pop_arg;
+/// type F = pop_arg!(unsafe extern "C" fn(*const u8, usize) -> bool);
+/// // `F` is `unsafe extern "C" fn(*const u8) -> bool`
+/// # static_assertions::assert_type_eq_all!(F, unsafe extern "C" fn(*const u8) -> bool);
+/// ```
+#[macro_export]
+macro_rules! pop_arg {
+    ( $ty:ty ) => {
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::PopArg>::F>
+    };
+}
+
+/// Construct a function-pointer type identical to the given one but with its arguments in reverse order
+/// (see [`ReverseArgs`]).
+///
+/// This only changes the type, not the calling convention, just like [`with_abi!`](crate::with_abi).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fn_ptr::reverse_args;
+/// type F = reverse_args!(unsafe extern "C" fn(*const u8, usize) -> i32);
+/// // `F` is `unsafe extern "C" fn(usize, *const u8) -> i32`
+/// # static_assertions::assert_type_eq_all!(F, unsafe extern "C" fn(usize, *const u8) -> i32);
+/// ```
+#[macro_export]
+macro_rules! reverse_args {
+    ( $ty:ty ) => {
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::ReverseArgs>::F>
+    };
+}
+
+/// Construct a function-pointer type identical to the given one but with the argument types at two
+/// indices swapped (see [`SwapArgs`]).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fn_ptr::swap_args;
+/// type F = unsafe fn(*mut u8, usize, u32) -> i32;
+/// type G = swap_args!(0, 2, F);
+/// // `G` is `unsafe fn(u32, usize, *mut u8) -> i32`
+/// # static_assertions::assert_type_eq_all!(G, unsafe fn(u32, usize, *mut u8) -> i32);
+/// ```
+#[macro_export]
+macro_rules! swap_args {
+    ( $i:expr, $j:expr, $ty:ty ) => {
+        <$ty as $crate::MaybeNullable>::Wrap<<<$ty as $crate::MaybeNullable>::Inner as $crate::SwapArgs<{ $i }, { $j }>>::F>
+    };
+}
+
+/// Casts a function pointer value to the given function pointer type.
+///
+/// `as_sig!(f, T)` is shorthand for [`FnPtr::cast::<T>(&f)`](crate::FnPtr::cast) and accepts any function
+/// pointer type including its safety, abi, arguments and output.
+/// Like `cast`, this is unsafe and has to be used inside an `unsafe` block.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fn_ptr::as_sig;
+/// extern "C" fn add(a: i32, b: i32) -> i32 { a + b }
+///
+/// let f = add as extern "C" fn(i32, i32) -> i32;
+/// let g = unsafe { as_sig!(f, unsafe extern "C" fn(u32, u32) -> u32) };
+/// assert_eq!(unsafe { g(1, 2) }, 3);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use fn_ptr::as_sig;
+/// let f: fn() = || {};
+/// let g = as_sig!(f, extern "C" fn()); // error: requires an unsafe block
+/// ```
+#[macro_export]
+macro_rules! as_sig {
+    ( $f:expr, $ty:ty ) => {
+        $crate::FnPtr::cast::<$ty>(&$f)
+    };
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(not(has_arity_7))]
+    #[test]
+    fn append_arg_respects_max_arity() {
+        use crate::{AppendArg, PrependArg};
+        use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+        type F5 = fn(u8, u8, u8, u8, u8);
+        type F6 = fn(u8, u8, u8, u8, u8, u8);
+        assert_impl_all!(F5: AppendArg<u8>, PrependArg<u8>);
+        assert_not_impl_any!(F6: AppendArg<u8>, PrependArg<u8>);
+    }
+
+    #[cfg(all(has_arity_12, not(has_arity_13)))]
+    #[test]
+    fn append_arg_respects_max_arity_12() {
+        use crate::{AppendArg, PrependArg};
+        use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+        type F11 = fn(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+        type F12 = fn(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+        assert_impl_all!(F11: AppendArg<u8>, PrependArg<u8>);
+        assert_not_impl_any!(F12: AppendArg<u8>, PrependArg<u8>);
+    }
+}
@@ -0,0 +1,123 @@
+use alloc::borrow::Cow;
+use core::fmt;
+
+use gimli::{EndianSlice, RunTimeEndian};
+use object::{BinaryFormat, Object, ObjectSection, ObjectSegment};
+
+/// Error returned by [`FnPtr::verify_against_debuginfo`](crate::FnPtr::verify_against_debuginfo).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SignatureMismatch {
+    /// The number of parameters recorded in the debug info differs from the declared arity.
+    Arity {
+        /// The arity of the function pointer type.
+        expected: usize,
+        /// The number of parameters recorded in the debug info.
+        found: usize,
+    },
+    /// No function containing the address was found in the debug info.
+    NotFound,
+    /// The module or its debug info could not be parsed.
+    InvalidModule,
+}
+
+impl fmt::Display for SignatureMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureMismatch::Arity { expected, found } => write!(
+                f,
+                "function takes {found} parameters according to debug info, but {expected} were declared"
+            ),
+            SignatureMismatch::NotFound => f.write_str("function not found in debug info"),
+            SignatureMismatch::InvalidModule => f.write_str("failed to parse module debug info"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SignatureMismatch {}
+
+impl From<gimli::Error> for SignatureMismatch {
+    fn from(_: gimli::Error) -> Self {
+        SignatureMismatch::InvalidModule
+    }
+}
+
+/// Returns the link time address of the start of the loaded image of `file`.
+///
+/// For PE this is the preferred image base. For ELF it is the address of the lowest `PT_LOAD`
+/// segment, which is 0 for position independent executables and shared libraries, but e.g.
+/// `0x400000` for non-PIE executables on `x86_64` linux. `object` reports a relative address base of
+/// 0 for all ELF files, so it is computed from the segments instead.
+fn link_base(file: &object::File<'_>) -> u64 {
+    match file.format() {
+        BinaryFormat::Elf => file
+            .segments()
+            .map(|segment| segment.address())
+            .min()
+            .unwrap_or(0),
+        _ => file.relative_address_base(),
+    }
+}
+
+/// Looks up the subprogram containing `rva` in the DWARF info of `module` and compares its
+/// parameter count against `arity`.
+pub(crate) fn verify_arity(module: &[u8], rva: u64, arity: usize) -> Result<(), SignatureMismatch> {
+    let file = object::File::parse(module).map_err(|_| SignatureMismatch::InvalidModule)?;
+    let endian = if file.is_little_endian() {
+        RunTimeEndian::Little
+    } else {
+        RunTimeEndian::Big
+    };
+    // debug info addresses are link time addresses, not offsets into the module.
+    let addr = link_base(&file)
+        .checked_add(rva)
+        .ok_or(SignatureMismatch::NotFound)?;
+
+    let sections = gimli::DwarfSections::load(|id| -> Result<Cow<'_, [u8]>, SignatureMismatch> {
+        match file.section_by_name(id.name()) {
+            Some(section) => section
+                .uncompressed_data()
+                .map_err(|_| SignatureMismatch::InvalidModule),
+            None => Ok(Cow::Borrowed(&[])),
+        }
+    })?;
+    let dwarf = sections.borrow(|section| EndianSlice::new(section, endian));
+
+    let mut units = dwarf.units();
+    while let Some(header) = units.next()? {
+        let unit = dwarf.unit(header)?;
+        let mut entries = unit.entries();
+        while let Some((_, entry)) = entries.next_dfs()? {
+            if entry.tag() != gimli::DW_TAG_subprogram {
+                continue;
+            }
+            let mut ranges = dwarf.die_ranges(&unit, entry)?;
+            let mut contains = false;
+            while let Some(range) = ranges.next()? {
+                contains |= range.begin <= addr && addr < range.end;
+            }
+            if !contains {
+                continue;
+            }
+
+            let mut tree = unit.entries_tree(Some(entry.offset()))?;
+            let mut children = tree.root()?.children();
+            let mut found = 0;
+            while let Some(child) = children.next()? {
+                if child.entry().tag() == gimli::DW_TAG_formal_parameter {
+                    found += 1;
+                }
+            }
+            return if found == arity {
+                Ok(())
+            } else {
+                Err(SignatureMismatch::Arity {
+                    expected: arity,
+                    found,
+                })
+            };
+        }
+    }
+    Err(SignatureMismatch::NotFound)
+}
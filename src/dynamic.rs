@@ -0,0 +1,134 @@
+use core::fmt;
+
+use libffi::{
+    low::CodePtr,
+    middle::{Arg, Cif, FfiAbi, Type},
+    raw,
+};
+
+use crate::{AbiValue, UntypedFnPtr};
+
+/// Error returned by [`call_dynamic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DynamicCallError {
+    /// The abi is not supported by libffi on the current target.
+    UnsupportedAbi(AbiValue),
+    /// The number of argument values differs from the number of argument types.
+    ArgCount {
+        /// The number of argument types.
+        expected: usize,
+        /// The number of argument values.
+        found: usize,
+    },
+    /// libffi failed to prepare the call.
+    Ffi(libffi::low::Error),
+}
+
+impl fmt::Display for DynamicCallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynamicCallError::UnsupportedAbi(abi) => {
+                write!(f, "abi \"{abi}\" is not supported by libffi on this target")
+            }
+            DynamicCallError::ArgCount { expected, found } => {
+                write!(f, "expected {expected} arguments, but {found} were given")
+            }
+            DynamicCallError::Ffi(err) => write!(f, "libffi failed to prepare the call: {err:?}"),
+        }
+    }
+}
+
+impl std::error::Error for DynamicCallError {}
+
+impl From<libffi::low::Error> for DynamicCallError {
+    fn from(err: libffi::low::Error) -> Self {
+        DynamicCallError::Ffi(err)
+    }
+}
+
+/// Returns the libffi calling convention matching `abi` on the current target.
+///
+/// The abi is [canonized](AbiValue::canonize) first, so e.g. `system` maps to `stdcall` on 32-bit
+/// x86 windows. Unwinding variants map to the same calling convention as their non-unwinding counterpart.
+///
+/// Returns [`None`] if `abi` is not supported on the current target or has no libffi equivalent,
+/// e.g. `Rust` or `vectorcall`.
+#[must_use]
+pub fn libffi_abi(abi: AbiValue) -> Option<FfiAbi> {
+    #[allow(clippy::match_same_arms)]
+    let ffi_abi = match abi.canonize(false)? {
+        AbiValue::C { .. } => raw::ffi_abi_FFI_DEFAULT_ABI,
+        #[cfg(target_arch = "arm")]
+        AbiValue::Aapcs { .. } => raw::ffi_abi_FFI_SYSV,
+        #[cfg(target_arch = "aarch64")]
+        AbiValue::Aapcs { .. } => raw::ffi_abi_FFI_DEFAULT_ABI,
+        #[cfg(target_arch = "x86")]
+        AbiValue::Stdcall { .. } => raw::ffi_abi_FFI_STDCALL,
+        #[cfg(target_arch = "x86")]
+        AbiValue::Fastcall { .. } => raw::ffi_abi_FFI_FASTCALL,
+        #[cfg(target_arch = "x86")]
+        AbiValue::Thiscall { .. } => raw::ffi_abi_FFI_THISCALL,
+        #[cfg(all(target_arch = "x86_64", unix))]
+        AbiValue::SysV64 { .. } => raw::ffi_abi_FFI_UNIX64,
+        #[cfg(target_arch = "x86_64")]
+        AbiValue::Win64 { .. } => raw::ffi_abi_FFI_WIN64,
+        _ => return None,
+    };
+    Some(ffi_abi)
+}
+
+/// Calls the function at `ptr` using the calling convention `abi`, passing `args` described by
+/// `arg_types` and returning a value of type `R` described by `ret`.
+///
+/// This allows calling functions whose signature is only known at runtime, e.g. from a scripting host.
+///
+/// # Errors
+/// Returns an error if `abi` has no libffi equivalent on the current target (see [`libffi_abi`]),
+/// if `args` and `arg_types` differ in length or if libffi rejects the call description.
+///
+/// # Safety
+/// - `ptr` must point to a function using the abi `abi`, taking arguments of type `arg_types` and returning `ret`.
+/// - Each element of `args` must point to a value matching the corresponding element of `arg_types`.
+/// - `R` must match `ret`.
+/// - The function must not unwind, even if `abi` allows it.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{AbiValue, FnPtr, call_dynamic};
+/// # use libffi::middle::{Type, arg};
+/// extern "C" fn add(a: i32, b: i32) -> i32 {
+///     a + b
+/// }
+///
+/// let ptr = (add as extern "C" fn(i32, i32) -> i32).as_ptr();
+/// let sum: i32 = unsafe {
+///     call_dynamic(
+///         ptr,
+///         AbiValue::C { unwind: false },
+///         &[Type::i32(), Type::i32()],
+///         &[arg(&2i32), arg(&3i32)],
+///         Type::i32(),
+///     )
+/// }
+/// .unwrap();
+/// assert_eq!(sum, 5);
+/// ```
+pub unsafe fn call_dynamic<R>(
+    ptr: UntypedFnPtr,
+    abi: AbiValue,
+    arg_types: &[Type],
+    args: &[Arg<'_>],
+    ret: Type,
+) -> Result<R, DynamicCallError> {
+    if arg_types.len() != args.len() {
+        return Err(DynamicCallError::ArgCount {
+            expected: arg_types.len(),
+            found: args.len(),
+        });
+    }
+    let ffi_abi = libffi_abi(abi).ok_or(DynamicCallError::UnsupportedAbi(abi))?;
+    let cif = Cif::try_new_with_abi(arg_types.iter().cloned(), ret, ffi_abi)?;
+    Ok(unsafe { cif.call(CodePtr::from_ptr(ptr.cast()), args) })
+}
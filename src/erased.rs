@@ -0,0 +1,71 @@
+use core::hash::{Hash, Hasher};
+
+use crate::{FnPtr, StaticFnPtr, fnv::Fnv1a};
+
+/// A type-erased function pointer stored as `unsafe extern "C" fn()` together with a hash of its
+/// real type, which is checked before restoring it.
+///
+/// The hash is derived from the [`TypeId`](core::any::TypeId) of the function pointer type and is
+/// only stable within a single build.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::ErasedCallback;
+/// extern "C" fn add(a: i32, b: i32) -> i32 { a + b }
+///
+/// let erased = ErasedCallback::erase(add as extern "C" fn(i32, i32) -> i32);
+/// let f = unsafe { erased.restore::<extern "C" fn(i32, i32) -> i32>() }.unwrap();
+/// assert_eq!(f(2, 3), 5);
+/// assert!(unsafe { erased.restore::<extern "C" fn(i32) -> i32>() }.is_none());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ErasedCallback {
+    f: unsafe extern "C" fn(),
+    signature_hash: u64,
+}
+
+impl ErasedCallback {
+    /// Erases the given function pointer.
+    #[must_use]
+    pub fn erase<F: StaticFnPtr>(f: F) -> Self {
+        Self {
+            // SAFETY: the pointer is only called after being restored to its original type.
+            f: unsafe { f.cast() },
+            signature_hash: signature_hash::<F>(),
+        }
+    }
+
+    /// Restores the erased function pointer as `F`, returning [`None`] if `F` is not the type it
+    /// was erased from.
+    ///
+    /// # Safety
+    /// The hash check is a sanity check only. A hash collision would reinterpret the function
+    /// pointer as a type with a different signature.
+    #[must_use]
+    pub unsafe fn restore<F: StaticFnPtr>(&self) -> Option<F> {
+        if self.signature_hash == signature_hash::<F>() {
+            Some(unsafe { self.f.cast() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the erased function pointer.
+    #[must_use]
+    pub const fn as_erased(&self) -> unsafe extern "C" fn() {
+        self.f
+    }
+
+    /// Returns the hash of the type the function pointer was erased from.
+    #[must_use]
+    pub const fn signature_hash(&self) -> u64 {
+        self.signature_hash
+    }
+}
+
+fn signature_hash<F: StaticFnPtr>() -> u64 {
+    let mut hasher = Fnv1a::new();
+    core::any::TypeId::of::<F>().hash(&mut hasher);
+    hasher.finish()
+}
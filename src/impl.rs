@@ -77,6 +77,8 @@ macro_rules! impl_fn {
 
     // core macro
     (@impl_core ($($nm:ident : $ty:ident),*), $fn_type:ty, $safety:tt, $abi_ident:ident, $call_conv:expr) => {
+        impl<Output, $($ty),*> $crate::base::sealed::Sealed for $fn_type {}
+
         #[automatically_derived]
         impl<Output, $($ty),*> $crate::FnPtr for $fn_type {
             type Args = ($($ty,)*);
@@ -85,10 +87,17 @@ macro_rules! impl_fn {
             type Safety = $crate::safety!($safety);
             type Abi = $crate::abi::$abi_ident;
 
+            type Safe = extern $call_conv fn($($ty),*) -> Output;
+            type Unsafe = unsafe extern $call_conv fn($($ty),*) -> Output;
+            type WithRust = impl_fn!(@make_unsafe extern "Rust" fn($($ty),*) -> Output, $safety);
+            type WithC = impl_fn!(@make_unsafe extern "C" fn($($ty),*) -> Output, $safety);
+
             const ARITY: ::core::primitive::usize = <<Self::Args as $crate::tuple::Tuple>::Arity as $crate::arity::Arity>::N;
             const IS_SAFE: ::core::primitive::bool = <Self::Safety as $crate::safety::Safety>::IS_SAFE;
             const ABI: $crate::AbiValue = <$crate::abi::$abi_ident as $crate::abi::Abi>::VALUE;
             const IS_EXTERN: ::core::primitive::bool = !matches!(Self::ABI, $crate::AbiValue::Rust);
+            const ARGS_SIZE: ::core::primitive::usize = 0 $(+ ::core::mem::size_of::<$ty>())*;
+            const X86_STACK_ARGS_SIZE: ::core::primitive::usize = 0 $(+ ::core::mem::size_of::<$ty>().next_multiple_of(4))*;
 
             fn as_ptr(&self) -> $crate::UntypedFnPtr {
                 *self as $crate::UntypedFnPtr
@@ -97,6 +106,9 @@ macro_rules! impl_fn {
                 ::core::assert!(!ptr.is_null());
                 unsafe { ::core::mem::transmute::<$crate::UntypedFnPtr, Self>(ptr) }
             }
+            fn arg_type_names() -> $crate::ArgArray<Self, &'static ::core::primitive::str> {
+                [$(::core::any::type_name::<$ty>()),*]
+            }
         }
         impl_fn!(@impl_safe_fn_type ($($nm : $ty),*), $fn_type, $safety);
 
@@ -209,14 +221,29 @@ macro_rules! impl_fn {
 }
 
 // Default: generate impls up to 6 arguments
-#[cfg(not(feature = "max-arity-12"))]
 impl_fn! {
     __arg_0: A, __arg_1: B, __arg_2: C, __arg_3: D, __arg_4: E, __arg_5: F
 }
 
-// Optional: generate impls up to 12 arguments when feature is enabled
-#[cfg(feature = "max-arity-12")]
-impl_fn! {
-    __arg_0: A, __arg_1: B, __arg_2: C, __arg_3: D, __arg_4: E, __arg_5: F, __arg_6: G,
-    __arg_7: H, __arg_8: I, __arg_9: J, __arg_10: K, __arg_11: L
-}
+// Optional: generate impls for higher arities enabled by the build script
+// (up to 12 with feature `max-arity-12`, or up to `FN_PTR_MAX_ARITY`).
+#[cfg(has_arity_7)]
+impl_fn!(@impl_all (__arg_0: A, __arg_1: B, __arg_2: C, __arg_3: D, __arg_4: E, __arg_5: F, __arg_6: G));
+#[cfg(has_arity_8)]
+impl_fn!(@impl_all (__arg_0: A, __arg_1: B, __arg_2: C, __arg_3: D, __arg_4: E, __arg_5: F, __arg_6: G, __arg_7: H));
+#[cfg(has_arity_9)]
+impl_fn!(@impl_all (__arg_0: A, __arg_1: B, __arg_2: C, __arg_3: D, __arg_4: E, __arg_5: F, __arg_6: G, __arg_7: H, __arg_8: I));
+#[cfg(has_arity_10)]
+impl_fn!(@impl_all (__arg_0: A, __arg_1: B, __arg_2: C, __arg_3: D, __arg_4: E, __arg_5: F, __arg_6: G, __arg_7: H, __arg_8: I, __arg_9: J));
+#[cfg(has_arity_11)]
+impl_fn!(@impl_all (__arg_0: A, __arg_1: B, __arg_2: C, __arg_3: D, __arg_4: E, __arg_5: F, __arg_6: G, __arg_7: H, __arg_8: I, __arg_9: J, __arg_10: K));
+#[cfg(has_arity_12)]
+impl_fn!(@impl_all (__arg_0: A, __arg_1: B, __arg_2: C, __arg_3: D, __arg_4: E, __arg_5: F, __arg_6: G, __arg_7: H, __arg_8: I, __arg_9: J, __arg_10: K, __arg_11: L));
+#[cfg(has_arity_13)]
+impl_fn!(@impl_all (__arg_0: A, __arg_1: B, __arg_2: C, __arg_3: D, __arg_4: E, __arg_5: F, __arg_6: G, __arg_7: H, __arg_8: I, __arg_9: J, __arg_10: K, __arg_11: L, __arg_12: M));
+#[cfg(has_arity_14)]
+impl_fn!(@impl_all (__arg_0: A, __arg_1: B, __arg_2: C, __arg_3: D, __arg_4: E, __arg_5: F, __arg_6: G, __arg_7: H, __arg_8: I, __arg_9: J, __arg_10: K, __arg_11: L, __arg_12: M, __arg_13: N));
+#[cfg(has_arity_15)]
+impl_fn!(@impl_all (__arg_0: A, __arg_1: B, __arg_2: C, __arg_3: D, __arg_4: E, __arg_5: F, __arg_6: G, __arg_7: H, __arg_8: I, __arg_9: J, __arg_10: K, __arg_11: L, __arg_12: M, __arg_13: N, __arg_14: O));
+#[cfg(has_arity_16)]
+impl_fn!(@impl_all (__arg_0: A, __arg_1: B, __arg_2: C, __arg_3: D, __arg_4: E, __arg_5: F, __arg_6: G, __arg_7: H, __arg_8: I, __arg_9: J, __arg_10: K, __arg_11: L, __arg_12: M, __arg_13: N, __arg_14: O, __arg_15: P));
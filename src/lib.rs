@@ -1,4 +1,4 @@
-#![cfg_attr(nightly_build, fn_ptr_trait)]
+#![cfg_attr(nightly_build, feature(fn_ptr_trait, tuple_trait))]
 #![cfg_attr(has_abi_vectorcall, feature(abi_vectorcall))]
 #![warn(clippy::pedantic, missing_docs)]
 #![no_std]
@@ -22,6 +22,7 @@
 //! assert_eq!(<F as FnPtr>::ARITY, 2);
 //! assert_eq!(<F as FnPtr>::IS_SAFE, true);
 //! assert_eq!(<F as FnPtr>::IS_EXTERN, true);
+//! assert_eq!(<F as FnPtr>::IS_UNWIND, false);
 //! assert_eq!(<F as FnPtr>::ABI, AbiValue::C { unwind: false });
 //! ```
 //!
@@ -33,6 +34,7 @@
 //! const A: usize = fn_ptr::arity::<F>();
 //! const SAFE: bool = fn_ptr::is_safe::<F>();
 //! const EXT: bool = fn_ptr::is_extern::<F>();
+//! const UNWIND: bool = fn_ptr::is_unwind::<F>();
 //! const ABI: AbiValue = fn_ptr::abi::<F>();
 //! ```
 //!
@@ -40,10 +42,12 @@
 //!
 //! The crate provides type-level rewriting via traits:
 //!
-//! - **abi:** [`WithAbi`] / [`with_abi!`]
+//! - **abi:** [`WithAbi`] / [`with_abi!`] ([`with_unwind!`])
 //! - **Safety:** [`WithSafety`] / [`with_safety!`] ([`make_safe!`], [`make_unsafe!`])
 //! - **Output:** [`WithOutput`] / [`with_output!`]
-//! - **Args:** [`WithArgs`] / [`with_args!`]
+//! - **Args:** [`WithArgs`] / [`with_args!`] ([`with_arg!`], [`prepend_arg!`], [`append_arg!`], [`pop_arg!`], [`flatten_args!`], [`swap_args!`], [`reverse_args!`])
+//! - **Multiple at once:** [`Reshape`]
+//! - **Safe/unsafe/nullable aliases:** [`fn_types!`]
 //!
 //! ### Type-level transformations
 //!
@@ -93,9 +97,14 @@
 //! Implementations are generated by a large [macro](https://github.com/OpenByteDev/fn-ptr/blob/master/src/impl.rs). The rewrite macros are thin wrappers
 //! over the traits [`WithAbi`], [`WithSafety`], [`WithOutput`], [`WithArgs`] (and the corresponding `*Impl` helper traits).
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 /// Module containing the Abi abstraction.
 mod abi_value;
-pub use abi_value::AbiValue;
+pub use abi_value::{AbiMismatch, AbiValue, ExternClause};
 
 mod r#impl;
 
@@ -124,6 +133,104 @@ pub use tuple::*;
 mod conv;
 pub use conv::*;
 
+mod callback;
+pub use callback::*;
+
+mod unwind;
+pub use unwind::*;
+
+mod resolve;
+pub use resolve::*;
+
+mod bind;
+pub use bind::*;
+
+mod erased;
+pub use erased::*;
+
+mod signature;
+pub use signature::*;
+
+mod result_fn;
+pub use result_fn::*;
+
+#[cfg(feature = "std")]
+mod canary;
+#[cfg(feature = "std")]
+pub use canary::*;
+
+mod transparent;
+pub use transparent::*;
+
+mod optional;
+pub use optional::*;
+
+#[cfg(feature = "alloc")]
+mod observer;
+#[cfg(feature = "alloc")]
+pub use observer::*;
+
+mod fnv;
+
+#[cfg(feature = "alloc")]
+mod mangle;
+
+mod waker;
+
+#[cfg(feature = "std")]
+mod memoized;
+#[cfg(feature = "std")]
+pub use memoized::*;
+
+#[cfg(feature = "std")]
+mod module;
+
+#[cfg(feature = "std")]
+mod trampoline;
+#[cfg(feature = "std")]
+pub use trampoline::*;
+
+#[cfg(feature = "linkme")]
+mod registry;
+#[cfg(feature = "linkme")]
+pub use registry::*;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+#[cfg(feature = "libffi")]
+mod dynamic;
+#[cfg(feature = "libffi")]
+pub use dynamic::*;
+
+#[cfg(feature = "debuginfo")]
+mod debuginfo;
+#[cfg(feature = "debuginfo")]
+pub use debuginfo::SignatureMismatch;
+
+#[cfg(target_has_atomic = "ptr")]
+mod atomic;
+#[cfg(target_has_atomic = "ptr")]
+pub use atomic::*;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use paste::paste;
+
+    #[cfg(feature = "linkme")]
+    pub use linkme;
+
+    pub use crate::waker::waker_slot;
+
+    #[must_use]
+    pub const fn arity_of<F: crate::FnPtr>(_f: &F) -> usize {
+        F::ARITY
+    }
+}
+
+#[cfg(feature = "test-util")]
+mod test_util;
+
 /// Returns the number of arguments of a function pointer type.
 #[must_use]
 pub const fn arity<F: FnPtr>() -> usize {
@@ -148,8 +255,182 @@ pub const fn is_extern<F: FnPtr>() -> bool {
     F::IS_EXTERN
 }
 
+/// Returns the full signature of the function pointer type as a string, e.g.
+/// `unsafe extern "C" fn(i32, u8) -> bool` (see [`FnPtr::write_signature`]).
+///
+/// Only available with the `alloc` feature.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(fn_ptr::signature_string::<unsafe fn(u8) -> u16>(), "unsafe fn(u8) -> u16");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn signature_string<F: FnPtr>() -> alloc::string::String {
+    let mut s = alloc::string::String::new();
+    // writing to a `String` cannot fail
+    let _ = F::write_signature(&mut s);
+    s
+}
+
+/// Returns `true` if the abi of the function pointer allows unwinding.
+#[must_use]
+pub const fn is_unwind<F: FnPtr>() -> bool {
+    F::IS_UNWIND
+}
+
 /// Returns a runtime representation of the abi of the function pointer.
 #[must_use]
 pub const fn abi<F: FnPtr>() -> AbiValue {
     F::ABI
 }
+
+/// Returns `true` if reinterpreting `F` with the abi `A` does not change the calling convention
+/// on the current target, i.e. both abis canonize to the same concrete abi.
+///
+/// In that case a cast via [`FnPtr::with_abi`] is sufficient and no trampoline is needed.
+/// Returns `false` if either abi is not supported on the current target.
+///
+/// ```rust
+/// # use fn_ptr::{abi, abi_transition_is_noop};
+/// const NOOP: bool = abi_transition_is_noop::<extern "C" fn(), abi!("cdecl")>();
+/// assert!(NOOP);
+/// assert!(!abi_transition_is_noop::<extern "C" fn(), abi!("C-unwind")>());
+/// ```
+#[must_use]
+pub const fn abi_transition_is_noop<F: FnPtr, A: abi::Abi>() -> bool {
+    // function pointer types implementing `FnPtr` are never variadic.
+    match (F::ABI.canonize(false), A::VALUE.canonize(false)) {
+        (Some(from), Some(to)) => from.index() == to.index(),
+        _ => false,
+    }
+}
+
+/// Returns `true` if a function of type `To` can not directly replace a function of type `From`
+/// on the current target, because their abis canonize to different concrete abis or differ in
+/// whether they allow unwinding.
+///
+/// This is the negation of [`abi_transition_is_noop`] for the abi of `To`, so unsupported abis
+/// always require a trampoline.
+///
+/// ```rust
+/// # use fn_ptr::requires_trampoline;
+/// assert!(!requires_trampoline::<extern "C" fn(i32), extern "C" fn(i32)>());
+/// assert!(requires_trampoline::<extern "C" fn(i32), fn(i32)>());
+/// assert!(requires_trampoline::<extern "C" fn(i32), extern "C-unwind" fn(i32)>());
+/// ```
+#[must_use]
+pub const fn requires_trampoline<From: FnPtr, To: FnPtr>() -> bool {
+    !abi_transition_is_noop::<From, To::Abi>()
+}
+
+/// Returns `true` if converting a function pointer of type `From` to `To` would remove the ability to
+/// unwind, i.e. `From` allows unwinding but `To` does not (see [`Abi::ALLOWS_UNWIND`]).
+///
+/// Calling a function that panics through such a converted pointer is undefined behavior.
+/// [`assert_unwind_preserving!`] turns this into a compile time check.
+///
+/// ```rust
+/// # use fn_ptr::changes_unwind;
+/// assert!(changes_unwind::<fn(), extern "C" fn()>());
+/// assert!(!changes_unwind::<fn(), extern "C-unwind" fn()>());
+/// assert!(!changes_unwind::<extern "C" fn(), fn()>());
+/// ```
+#[must_use]
+pub const fn changes_unwind<From: FnPtr, To: FnPtr>() -> bool {
+    <From::Abi as Abi>::ALLOWS_UNWIND && !<To::Abi as Abi>::ALLOWS_UNWIND
+}
+
+/// Returns the abi strings for which function pointer types implement [`FnPtr`] in this build,
+/// i.e. the abis accepted by [`with_abi!`] on the current target.
+#[must_use]
+pub const fn supported_abi_strings() -> &'static [&'static str] {
+    // NOTE: keep in sync with `impl_fn!(@impl_all ..)` in impl.rs.
+    &[
+        "Rust",
+        "C",
+        "C-unwind",
+        "system",
+        "system-unwind",
+        #[cfg(has_abi_cdecl)]
+        "cdecl",
+        #[cfg(has_abi_cdecl)]
+        "cdecl-unwind",
+        #[cfg(has_abi_stdcall)]
+        "stdcall",
+        #[cfg(has_abi_stdcall)]
+        "stdcall-unwind",
+        #[cfg(has_abi_fastcall)]
+        "fastcall",
+        #[cfg(has_abi_fastcall)]
+        "fastcall-unwind",
+        #[cfg(has_abi_thiscall)]
+        "thiscall",
+        #[cfg(has_abi_thiscall)]
+        "thiscall-unwind",
+        #[cfg(has_abi_vectorcall)]
+        "vectorcall",
+        #[cfg(has_abi_vectorcall)]
+        "vectorcall-unwind",
+        #[cfg(has_abi_win64)]
+        "win64",
+        #[cfg(has_abi_win64)]
+        "win64-unwind",
+        #[cfg(has_abi_sysv64)]
+        "sysv64",
+        #[cfg(has_abi_sysv64)]
+        "sysv64-unwind",
+        #[cfg(has_abi_aapcs)]
+        "aapcs",
+        #[cfg(has_abi_aapcs)]
+        "aapcs-unwind",
+        #[cfg(has_abi_efiapi)]
+        "efiapi",
+    ]
+}
+
+/// Returns the untyped pointer of a function pointer in a `const` context.
+///
+/// This is the same as [`FnPtr::as_ptr`], which can not be called in `const` contexts as it is a trait method.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{UntypedFnPtr, as_const_ptr};
+/// fn a() {}
+/// extern "C" fn b(_: i32) {}
+///
+/// const TABLE: [UntypedFnPtr; 2] = [as_const_ptr(a as fn()), as_const_ptr(b as extern "C" fn(i32))];
+/// ```
+#[must_use]
+pub const fn as_const_ptr<F: FnPtr>(f: F) -> UntypedFnPtr {
+    #[repr(C)]
+    union Transmute<F: Copy> {
+        f: F,
+        ptr: UntypedFnPtr,
+    }
+
+    const {
+        assert!(size_of::<F>() == size_of::<UntypedFnPtr>());
+    }
+    // SAFETY: function pointers have the same size and layout as untyped pointers.
+    unsafe { Transmute { f }.ptr }
+}
+
+/// Decodes a [`FnPtr::DISCRIMINANT`] into its arity, abi and safety.
+///
+/// Returns [`None`] if the value is not a valid discriminant.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn from_discriminant(discriminant: u32) -> Option<(usize, AbiValue, bool)> {
+    if discriminant >> 17 != 0 {
+        return None;
+    }
+    let arity = (discriminant & 0xFF) as usize;
+    let Some(abi) = AbiValue::from_index((discriminant >> 8) as u8) else {
+        return None;
+    };
+    let is_safe = discriminant & (1 << 16) != 0;
+    Some((arity, abi, is_safe))
+}
@@ -0,0 +1,83 @@
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::{FnPtr, abi::Abi};
+
+/// Encodes the signature of `F` in a scheme modelled after the Itanium C++ ABI function type
+/// encoding.
+///
+/// The return and argument types are encoded as `F <ret> <args> E`, with `v` for an empty
+/// argument list. A `()` argument is encoded as the vendor extended type `u4unit`, so it can not
+/// be confused with an empty argument list. Primitives use their Itanium builtin codes, pointers
+/// and references are encoded as `P`/`R` (with `K` for `const`) and all other types as a length
+/// prefixed type name. Safety and non-`"C"` abis are encoded as vendor qualifiers (`U6unsafe`,
+/// `U4Rust`, ...) in front.
+pub(crate) fn mangle<F: FnPtr>() -> String {
+    let mut out = String::new();
+    if !F::IS_SAFE {
+        push_source_name(&mut out, "U", "unsafe");
+    }
+    if <F::Abi as Abi>::STR != "C" {
+        push_source_name(&mut out, "U", <F::Abi as Abi>::STR);
+    }
+    out.push('F');
+    push_type(&mut out, core::any::type_name::<F::Output>());
+    if F::ARITY == 0 {
+        out.push('v');
+    } else {
+        for name in F::arg_type_names() {
+            if name == "()" {
+                push_source_name(&mut out, "u", "unit");
+            } else {
+                push_type(&mut out, name);
+            }
+        }
+    }
+    out.push('E');
+    out
+}
+
+fn push_type(out: &mut String, name: &str) {
+    let builtin = match name {
+        "()" => "v",
+        "bool" => "b",
+        "char" => "Di",
+        "i8" => "a",
+        "u8" => "h",
+        "i16" => "s",
+        "u16" => "t",
+        "i32" => "i",
+        "u32" => "j",
+        "i64" => "x",
+        "u64" => "y",
+        "i128" => "n",
+        "u128" => "o",
+        "isize" => "l",
+        "usize" => "m",
+        "f32" => "f",
+        "f64" => "d",
+        _ => "",
+    };
+    if !builtin.is_empty() {
+        out.push_str(builtin);
+    } else if let Some(pointee) = name.strip_prefix("*const ") {
+        out.push_str("PK");
+        push_type(out, pointee);
+    } else if let Some(pointee) = name.strip_prefix("*mut ") {
+        out.push('P');
+        push_type(out, pointee);
+    } else if let Some(pointee) = name.strip_prefix("&mut ") {
+        out.push('R');
+        push_type(out, pointee);
+    } else if let Some(pointee) = name.strip_prefix('&') {
+        out.push_str("RK");
+        push_type(out, pointee);
+    } else {
+        push_source_name(out, "", name);
+    }
+}
+
+fn push_source_name(out: &mut String, prefix: &str, name: &str) {
+    // writing to a `String` cannot fail
+    let _ = write!(out, "{prefix}{}{name}", name.len());
+}
@@ -0,0 +1,77 @@
+use core::{cell::RefCell, fmt, hash::Hash};
+use std::collections::HashMap;
+
+use crate::SafeFnPtr;
+
+/// A wrapper around a pure function pointer which caches its results keyed on the arguments.
+///
+/// Only available with the `std` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::Memoized;
+/// fn fib(n: u64) -> u64 { if n < 2 { n } else { fib(n - 1) + fib(n - 2) } }
+///
+/// let fib = Memoized::new(fib as fn(u64) -> u64);
+/// assert_eq!(fib.invoke((30,)), 832_040);
+/// assert_eq!(fib.invoke((30,)), 832_040); // cached
+/// assert_eq!(fib.cached_len(), 1);
+/// ```
+pub struct Memoized<F: SafeFnPtr> {
+    f: F,
+    cache: RefCell<HashMap<F::Args, F::Output>>,
+}
+
+impl<F: SafeFnPtr> Memoized<F>
+where
+    F::Args: Eq + Hash + Clone,
+    F::Output: Clone,
+{
+    /// Wraps `f` with an empty cache.
+    ///
+    /// `f` has to be pure, i.e. always return the same output for the same arguments.
+    #[must_use]
+    pub fn new(f: F) -> Self {
+        Self {
+            f,
+            cache: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the cached output for `args`, invoking the function and caching its output on a miss.
+    pub fn invoke(&self, args: F::Args) -> F::Output {
+        if let Some(output) = self.cache.borrow().get(&args) {
+            return output.clone();
+        }
+        // the cache is not borrowed during the call, so a panic leaves it untouched.
+        let output = self.f.invoke(args.clone());
+        self.cache.borrow_mut().insert(args, output.clone());
+        output
+    }
+
+    /// Returns the wrapped function pointer.
+    pub fn fn_ptr(&self) -> F {
+        self.f
+    }
+
+    /// Returns the number of cached results.
+    #[must_use]
+    pub fn cached_len(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&mut self) {
+        self.cache.get_mut().clear();
+    }
+}
+
+impl<F: SafeFnPtr> fmt::Debug for Memoized<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Memoized")
+            .field("f", &self.f)
+            .field("cached", &self.cache.borrow().len())
+            .finish()
+    }
+}
@@ -0,0 +1,78 @@
+use std::path::PathBuf;
+
+/// Returns the path of the loaded module (executable or shared library) containing `addr`.
+#[cfg(unix)]
+pub(crate) fn module_path_of(addr: *const core::ffi::c_void) -> Option<PathBuf> {
+    use core::ffi::{CStr, c_char, c_int, c_void};
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    // `Dl_info`
+    #[repr(C)]
+    struct DlInfo {
+        fname: *const c_char,
+        fbase: *mut c_void,
+        sname: *const c_char,
+        saddr: *mut c_void,
+    }
+
+    unsafe extern "C" {
+        fn dladdr(addr: *const c_void, info: *mut DlInfo) -> c_int;
+    }
+
+    let mut info = DlInfo {
+        fname: core::ptr::null(),
+        fbase: core::ptr::null_mut(),
+        sname: core::ptr::null(),
+        saddr: core::ptr::null_mut(),
+    };
+    if unsafe { dladdr(addr, &raw mut info) } == 0 || info.fname.is_null() {
+        return None;
+    }
+    let name = unsafe { CStr::from_ptr(info.fname) };
+    Some(PathBuf::from(OsStr::from_bytes(name.to_bytes())))
+}
+
+/// Returns the path of the loaded module (executable or dll) containing `addr`.
+#[cfg(windows)]
+pub(crate) fn module_path_of(addr: *const core::ffi::c_void) -> Option<PathBuf> {
+    use core::ffi::c_void;
+    use std::{ffi::OsString, os::windows::ffi::OsStringExt};
+
+    const GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT: u32 = 0x2;
+    const GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS: u32 = 0x4;
+
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn GetModuleHandleExW(flags: u32, module_name: *const u16, module: *mut *mut c_void)
+        -> i32;
+        fn GetModuleFileNameW(module: *mut c_void, filename: *mut u16, size: u32) -> u32;
+    }
+
+    let mut module = core::ptr::null_mut();
+    let flags =
+        GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS | GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT;
+    if unsafe { GetModuleHandleExW(flags, addr.cast(), &raw mut module) } == 0 {
+        return None;
+    }
+
+    let mut buf = std::vec![0u16; 260];
+    loop {
+        let size = u32::try_from(buf.len()).ok()?;
+        let len = unsafe { GetModuleFileNameW(module, buf.as_mut_ptr(), size) };
+        if len == 0 {
+            return None;
+        }
+        if len < size {
+            buf.truncate(len as usize);
+            return Some(PathBuf::from(OsString::from_wide(&buf)));
+        }
+        // truncated, retry with a larger buffer.
+        buf.resize(buf.len() * 2, 0);
+    }
+}
+
+/// Module lookup is not supported on this platform.
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn module_path_of(_addr: *const core::ffi::c_void) -> Option<PathBuf> {
+    None
+}
@@ -0,0 +1,133 @@
+use alloc::vec::Vec;
+
+use crate::SafeFnPtr;
+
+/// A list of callbacks with the same signature which are all invoked by [`notify`](ObserverList::notify).
+///
+/// Only available with the `alloc` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{FnPtr, ObserverList};
+/// # use std::sync::atomic::{AtomicU32, Ordering};
+/// static TOTAL: AtomicU32 = AtomicU32::new(0);
+///
+/// fn add(x: u32) { TOTAL.fetch_add(x, Ordering::Relaxed); }
+/// fn add_twice(x: u32) { TOTAL.fetch_add(2 * x, Ordering::Relaxed); }
+///
+/// let mut observers = ObserverList::<fn(u32)>::new();
+/// observers.subscribe(add);
+/// observers.subscribe(add_twice);
+/// observers.notify((1,));
+/// assert_eq!(TOTAL.load(Ordering::Relaxed), 3);
+///
+/// assert!(observers.unsubscribe_by_addr((add as fn(u32)).addr()));
+/// observers.notify((1,));
+/// assert_eq!(TOTAL.load(Ordering::Relaxed), 5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ObserverList<F: SafeFnPtr> {
+    observers: Vec<F>,
+}
+
+impl<F: SafeFnPtr> ObserverList<F> {
+    /// Creates an empty observer list.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            observers: Vec::new(),
+        }
+    }
+
+    /// Adds an observer to the end of the list.
+    pub fn subscribe(&mut self, observer: F) {
+        self.observers.push(observer);
+    }
+
+    /// Removes the first observer with the given address.
+    ///
+    /// Returns `true` if an observer was removed.
+    pub fn unsubscribe_by_addr(&mut self, addr: usize) -> bool {
+        if let Some(index) = self.observers.iter().position(|f| f.addr() == addr) {
+            self.observers.remove(index);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the subscribed observers in the order they are invoked.
+    #[must_use]
+    pub fn as_slice(&self) -> &[F] {
+        &self.observers
+    }
+
+    /// Returns the number of subscribed observers.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.observers.len()
+    }
+
+    /// Returns `true` if no observers are subscribed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.observers.is_empty()
+    }
+
+    /// Invokes all observers in order with clones of `args`.
+    pub fn notify(&self, args: F::Args)
+    where
+        F::Args: Clone,
+    {
+        for observer in &self.observers {
+            observer.invoke(args.clone());
+        }
+    }
+
+    /// Invokes all observers in order with clones of `args`, continuing if an observer panics.
+    ///
+    /// Returns the panic payloads of all observers which panicked.
+    ///
+    /// Only available with the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn notify_catching(
+        &self,
+        args: F::Args,
+    ) -> Vec<alloc::boxed::Box<dyn core::any::Any + Send>>
+    where
+        F::Args: Clone,
+    {
+        self.observers
+            .iter()
+            .filter_map(|observer| {
+                let args = args.clone();
+                // the args are an owned clone which is dropped if the observer panics.
+                std::panic::catch_unwind(core::panic::AssertUnwindSafe(move || {
+                    observer.invoke(args)
+                }))
+                .err()
+            })
+            .collect()
+    }
+}
+
+impl<F: SafeFnPtr> Default for ObserverList<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: SafeFnPtr> Extend<F> for ObserverList<F> {
+    fn extend<I: IntoIterator<Item = F>>(&mut self, iter: I) {
+        self.observers.extend(iter);
+    }
+}
+
+impl<F: SafeFnPtr> FromIterator<F> for ObserverList<F> {
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        Self {
+            observers: iter.into_iter().collect(),
+        }
+    }
+}
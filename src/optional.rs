@@ -0,0 +1,125 @@
+use crate::{FnPtr, UntypedFnPtr};
+
+/// A nullable function pointer of type `F`, e.g. for optional callbacks in `#[repr(C)]` structs.
+///
+/// This is a thin wrapper around `Option<F>`, which is guaranteed to use the null pointer as its
+/// [`None`] representation, so `OptionalFnPtr<F>` has the same size and abi as `F` and a nullable C
+/// function pointer.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::OptionalFnPtr;
+/// #[repr(C)]
+/// struct Ops {
+///     on_event: OptionalFnPtr<extern "C" fn(i32)>,
+/// }
+///
+/// extern "C" fn on_event(_: i32) {}
+///
+/// let ops = Ops { on_event: OptionalFnPtr::some(on_event) };
+/// assert!(ops.on_event.is_some());
+/// assert!(OptionalFnPtr::<extern "C" fn(i32)>::NONE.as_ptr().is_null());
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OptionalFnPtr<F: FnPtr>(Option<F>);
+
+impl<F: FnPtr> OptionalFnPtr<F> {
+    /// The null function pointer.
+    pub const NONE: Self = Self(None);
+
+    /// Wraps the function pointer `f`.
+    #[must_use]
+    pub const fn some(f: F) -> Self {
+        Self(Some(f))
+    }
+
+    /// Wraps the given optional function pointer.
+    #[must_use]
+    pub const fn new(f: Option<F>) -> Self {
+        Self(f)
+    }
+
+    /// Constructs an instance from an address, which may be null.
+    ///
+    /// # Safety
+    /// If the address is not null, it has to point to a function of type `F`.
+    #[must_use]
+    pub unsafe fn from_addr(addr: usize) -> Self {
+        Self(unsafe { F::try_from_addr(addr) })
+    }
+
+    /// Constructs an instance from an untyped function pointer, which may be null.
+    ///
+    /// # Safety
+    /// If the pointer is not null, it has to point to a function of type `F`.
+    #[must_use]
+    pub unsafe fn from_ptr(ptr: UntypedFnPtr) -> Self {
+        Self(unsafe { F::try_from_ptr(ptr) })
+    }
+
+    /// Returns the untyped function pointer, which is null for [`NONE`](Self::NONE).
+    #[must_use]
+    pub const fn as_ptr(&self) -> UntypedFnPtr {
+        match self.0 {
+            Some(f) => crate::as_const_ptr(f),
+            None => core::ptr::null(),
+        }
+    }
+
+    /// Returns `true` if this is not null.
+    #[must_use]
+    pub const fn is_some(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Returns `true` if this is null.
+    #[must_use]
+    pub const fn is_none(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Returns the function pointer as an [`Option`].
+    #[must_use]
+    pub const fn get(self) -> Option<F> {
+        self.0
+    }
+
+    /// Returns the function pointer.
+    ///
+    /// # Panics
+    /// Panics if this is null.
+    #[must_use]
+    #[track_caller]
+    pub const fn unwrap(self) -> F {
+        match self.0 {
+            Some(f) => f,
+            None => panic!("called `OptionalFnPtr::unwrap()` on a null function pointer"),
+        }
+    }
+}
+
+impl<F: FnPtr> Default for OptionalFnPtr<F> {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+impl<F: FnPtr> From<F> for OptionalFnPtr<F> {
+    fn from(f: F) -> Self {
+        Self::some(f)
+    }
+}
+
+impl<F: FnPtr> From<Option<F>> for OptionalFnPtr<F> {
+    fn from(f: Option<F>) -> Self {
+        Self::new(f)
+    }
+}
+
+impl<F: FnPtr> From<OptionalFnPtr<F>> for Option<F> {
+    fn from(f: OptionalFnPtr<F>) -> Self {
+        f.get()
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{FnPtr, Signature, UntypedFnPtr};
+
+/// Link time registry of all functions registered with [`register_fn!`](crate::register_fn).
+#[doc(hidden)]
+#[linkme::distributed_slice]
+pub static REGISTERED_FNS: [RegisteredFn];
+
+/// Returns all functions registered with [`register_fn!`](crate::register_fn) in the final binary.
+///
+/// The order of the entries is unspecified.
+///
+/// Only available with the `linkme` feature.
+#[must_use]
+pub fn registered() -> &'static [RegisteredFn] {
+    &REGISTERED_FNS
+}
+
+/// An erased function pointer registered with [`register_fn!`](crate::register_fn), together with
+/// its name and [`Signature`].
+///
+/// Only available with the `linkme` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegisteredFn {
+    name: &'static str,
+    ptr: UntypedFnPtr,
+    signature: Signature,
+}
+
+// SAFETY: the pointer is a function pointer, which are `Send` and `Sync`.
+unsafe impl Send for RegisteredFn {}
+// SAFETY: see above.
+unsafe impl Sync for RegisteredFn {}
+
+impl RegisteredFn {
+    /// Creates an entry for the function `f` registered under `name`.
+    #[must_use]
+    pub const fn new<F: FnPtr>(name: &'static str, f: F) -> Self {
+        Self {
+            name,
+            ptr: crate::as_const_ptr(f),
+            signature: Signature::of::<F>(),
+        }
+    }
+
+    /// Returns the name the function was registered under.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the untyped pointer to the function.
+    #[must_use]
+    pub const fn as_ptr(&self) -> UntypedFnPtr {
+        self.ptr
+    }
+
+    /// Returns the signature of the function.
+    #[must_use]
+    pub const fn signature(&self) -> Signature {
+        self.signature
+    }
+
+    /// Returns the function as a function pointer of type `F` if its [`Signature`] matches `F`.
+    ///
+    /// # Safety
+    /// The signature only covers arity, abi and safety, so the argument and return types of `F`
+    /// have to match those of the registered function.
+    #[must_use]
+    pub unsafe fn cast<F: FnPtr>(&self) -> Option<F> {
+        if self.signature.matches::<F>() {
+            Some(unsafe { F::from_ptr(self.ptr) })
+        } else {
+            None
+        }
+    }
+}
+
+/// Registers a function in the link time registry returned by [`registered`](crate::registered).
+///
+/// The function has to be given with its function pointer type. It is registered under its path
+/// unless a name is given explicitly.
+///
+/// Only available with the `linkme` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{register_fn, registered};
+/// extern "C" fn plugin_init(version: u32) -> i32 { version as i32 }
+///
+/// register_fn!(plugin_init as extern "C" fn(u32) -> i32);
+/// register_fn!("init_alias", plugin_init as extern "C" fn(u32) -> i32);
+///
+/// let entry = registered().iter().find(|f| f.name() == "plugin_init").unwrap();
+/// let init = unsafe { entry.cast::<extern "C" fn(u32) -> i32>() }.unwrap();
+/// assert_eq!(init(3), 3);
+/// assert!(unsafe { entry.cast::<extern "C" fn()>() }.is_none());
+/// ```
+#[macro_export]
+macro_rules! register_fn {
+    ($f:path as $ty:ty) => {
+        $crate::register_fn!(::core::stringify!($f), $f as $ty);
+    };
+    ($name:expr, $f:path as $ty:ty) => {
+        const _: () = {
+            #[$crate::__private::linkme::distributed_slice($crate::REGISTERED_FNS)]
+            #[linkme(crate = $crate::__private::linkme)]
+            static ENTRY: $crate::RegisteredFn = $crate::RegisteredFn::new::<$ty>($name, $f as $ty);
+        };
+    };
+}
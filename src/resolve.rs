@@ -0,0 +1,182 @@
+use core::fmt::{self, Write};
+
+use crate::{AbiValue, FnPtr, UntypedFnPtr};
+
+/// A source of symbols which can be looked up by name, e.g. a dynamic library, a jit module
+/// or an in-memory image.
+///
+/// Implemented for `libloading::Library` with the `libloading` feature.
+pub trait SymbolResolver {
+    /// Looks up the symbol with the given name, returning [`None`] if it could not be found.
+    fn resolve(&self, name: &str) -> Option<UntypedFnPtr>;
+}
+
+/// Looks up the symbol with the given name in `resolver` and interprets it as a function pointer of type `F`.
+///
+/// Returns [`None`] if the symbol could not be resolved or resolved to null.
+///
+/// # Safety
+/// The resolved symbol must be a function with a signature matching `F`.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{FnPtr, SymbolResolver, UntypedFnPtr, resolve_fn};
+/// struct Exports;
+///
+/// impl SymbolResolver for Exports {
+///     fn resolve(&self, name: &str) -> Option<UntypedFnPtr> {
+///         extern "C" fn answer() -> i32 { 42 }
+///         match name {
+///             "answer" => Some((answer as extern "C" fn() -> i32).as_ptr()),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let answer: extern "C" fn() -> i32 = unsafe { resolve_fn(&Exports, "answer") }.unwrap();
+/// assert_eq!(answer(), 42);
+/// assert!(unsafe { resolve_fn::<extern "C" fn(), _>(&Exports, "missing") }.is_none());
+/// ```
+pub unsafe fn resolve_fn<F: FnPtr, R: SymbolResolver + ?Sized>(
+    resolver: &R,
+    name: &str,
+) -> Option<F> {
+    let ptr = resolver.resolve(name)?;
+    if ptr.is_null() {
+        return None;
+    }
+    Some(unsafe { F::from_ptr(ptr) })
+}
+
+/// Looks up the symbol for a function named `base_name` in `resolver`, trying the usual name
+/// decorations of its abi if the plain name can not be resolved, and interprets it as a function
+/// pointer of type `F`.
+///
+/// On 32-bit x86 windows the decorations are derived from the abi of `F` (after [canonization](AbiValue::canonize))
+/// and its [`X86_STACK_ARGS_SIZE`](FnPtr::X86_STACK_ARGS_SIZE), e.g. for a function taking 8 bytes of arguments:
+/// - `cdecl`: `_name`
+/// - `stdcall` (and `system`): `_name@8` and `name@8`
+/// - `fastcall`: `@name@8`
+/// - `vectorcall`: `name@@8`
+///
+/// On all other targets only `base_name` is tried.
+///
+/// Returns [`None`] if none of the candidates could be resolved.
+///
+/// # Safety
+/// The resolved symbol must be a function with a signature matching `F`.
+pub unsafe fn resolve_fn_decorated<F: FnPtr, R: SymbolResolver + ?Sized>(
+    resolver: &R,
+    base_name: &str,
+) -> Option<F> {
+    if let Some(f) = unsafe { resolve_fn(resolver, base_name) } {
+        return Some(f);
+    }
+    if !cfg!(all(target_arch = "x86", target_os = "windows")) {
+        return None;
+    }
+
+    // (prefix, separator before the argument size)
+    let candidates: &[(&str, Option<&str>)] = match F::ABI.canonize(false) {
+        Some(AbiValue::C { .. } | AbiValue::Cdecl { .. }) => &[("_", None)],
+        Some(AbiValue::Stdcall { .. }) => &[("_", Some("@")), ("", Some("@"))],
+        Some(AbiValue::Fastcall { .. }) => &[("@", Some("@"))],
+        Some(AbiValue::Vectorcall { .. }) => &[("", Some("@@"))],
+        _ => &[],
+    };
+    candidates.iter().find_map(|&(prefix, separator)| {
+        let mut name = NameBuf::new();
+        match separator {
+            Some(separator) => write!(
+                name,
+                "{prefix}{base_name}{separator}{}",
+                F::X86_STACK_ARGS_SIZE
+            ),
+            None => write!(name, "{prefix}{base_name}"),
+        }
+        .ok()?;
+        unsafe { resolve_fn(resolver, name.as_str()) }
+    })
+}
+
+/// Fixed size buffer for building decorated symbol names without allocating.
+struct NameBuf {
+    buf: [u8; 256],
+    len: usize,
+}
+
+impl NameBuf {
+    const fn new() -> Self {
+        Self {
+            buf: [0; 256],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // SAFETY: only complete `str`s are written to the buffer.
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+}
+
+impl Write for NameBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "libloading")]
+impl SymbolResolver for libloading::Library {
+    fn resolve(&self, name: &str) -> Option<UntypedFnPtr> {
+        // SAFETY: the symbol is only read as an untyped pointer.
+        let symbol = unsafe { self.get::<UntypedFnPtr>(name.as_bytes()) }.ok()?;
+        Some(*symbol)
+    }
+}
+
+/// Interprets a symbol loaded with `libloading` as a function pointer of type `F`.
+///
+/// The symbol can be loaded as any pointer type, e.g. `*const ()` or [`UntypedFnPtr`].
+///
+/// # Safety
+/// The symbol must be a non-null function with a signature matching `F`.
+#[cfg(feature = "libloading")]
+#[must_use]
+pub unsafe fn from_symbol<F: FnPtr, T>(symbol: &libloading::Symbol<'_, *const T>) -> F {
+    unsafe { F::from_ptr(symbol.cast()) }
+}
+
+/// Looks up the symbol with the given name in `lib` and interprets it as a function pointer of type `F`.
+///
+/// Unlike [`resolve_fn`] this keeps the error reported by `libloading`. A symbol resolving to null
+/// is reported as a failed lookup as well.
+///
+/// # Safety
+/// The resolved symbol must be a function with a signature matching `F`.
+/// The returned function pointer must not be called after `lib` has been unloaded.
+///
+/// # Errors
+/// Returns an error if the symbol could not be found or resolved to null.
+#[cfg(feature = "libloading")]
+pub unsafe fn load_fn<F: FnPtr>(
+    lib: &libloading::Library,
+    name: &str,
+) -> Result<F, libloading::Error> {
+    // SAFETY: the symbol is only read as an untyped pointer.
+    let symbol = unsafe { lib.get::<UntypedFnPtr>(name.as_bytes()) }?;
+    if symbol.is_null() {
+        return Err(if cfg!(windows) {
+            libloading::Error::GetProcAddressUnknown
+        } else {
+            libloading::Error::DlSymUnknown
+        });
+    }
+    Ok(unsafe { from_symbol(&symbol) })
+}
@@ -0,0 +1,101 @@
+use core::num::NonZeroI32;
+
+use crate::{FnPtr, SafeFnPtr, UnsafeFnPtr};
+
+/// Converts a C style status code into a [`Result`], treating `0` as success and any other value as an error.
+///
+/// # Errors
+/// Returns the code as an error if it is nonzero.
+pub const fn status_to_result(code: i32) -> Result<(), NonZeroI32> {
+    match NonZeroI32::new(code) {
+        Some(code) => Err(code),
+        None => Ok(()),
+    }
+}
+
+/// A function pointer whose return value is mapped into a [`Result`] when invoked, created by [`result_fn!`].
+///
+/// By default the function has to return an `i32` status code, which is mapped with [`status_to_result`].
+/// A custom mapping can be given with [`ResultFn::with_mapping`].
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::result_fn;
+/// extern "C" fn check(value: i32) -> i32 { if value < 0 { -22 } else { 0 } }
+///
+/// let check = result_fn!(check as extern "C" fn(i32) -> i32);
+/// assert_eq!(check.invoke((1,)), Ok(()));
+/// assert_eq!(check.invoke((-1,)).unwrap_err().get(), -22);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ResultFn<F, M = fn(i32) -> Result<(), NonZeroI32>> {
+    /// The wrapped function pointer.
+    pub f: F,
+    /// The mapping applied to the return value of `f`.
+    pub map: M,
+}
+
+impl<F: FnPtr<Output = i32>> ResultFn<F> {
+    /// Wraps `f`, mapping its status code return value with [`status_to_result`].
+    pub const fn new(f: F) -> Self {
+        Self {
+            f,
+            map: status_to_result,
+        }
+    }
+}
+
+impl<F: FnPtr, M> ResultFn<F, M> {
+    /// Wraps `f`, mapping its return value with `map`.
+    pub const fn with_mapping(f: F, map: M) -> Self {
+        Self { f, map }
+    }
+}
+
+impl<F: SafeFnPtr, M> ResultFn<F, M> {
+    /// Invokes the function with the given args and maps its return value.
+    pub fn invoke<R>(&self, args: F::Args) -> R
+    where
+        M: Fn(F::Output) -> R,
+    {
+        (self.map)(self.f.invoke(args))
+    }
+}
+
+impl<F: UnsafeFnPtr, M> ResultFn<F, M> {
+    /// Invokes the function with the given args and maps its return value.
+    ///
+    /// # Safety
+    /// Same as [`UnsafeFnPtr::invoke`].
+    pub unsafe fn invoke_unsafe<R>(&self, args: F::Args) -> R
+    where
+        M: Fn(F::Output) -> R,
+    {
+        (self.map)(unsafe { self.f.invoke(args) })
+    }
+}
+
+/// Wraps a function pointer in a [`ResultFn`], mapping its return value into a [`Result`] when invoked.
+///
+/// `result_fn!(f)` maps an `i32` status code with [`status_to_result`], `result_fn!(f, map)` uses the custom mapping `map`.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::result_fn;
+/// extern "C" fn read(len: usize) -> isize { if len > 4 { -1 } else { len as isize } }
+///
+/// let read = result_fn!(read as extern "C" fn(usize) -> isize, |n: isize| usize::try_from(n).map_err(|_| "read failed"));
+/// assert_eq!(read.invoke((3,)), Ok(3));
+/// assert_eq!(read.invoke((5,)), Err("read failed"));
+/// ```
+#[macro_export]
+macro_rules! result_fn {
+    ($f:expr $(,)?) => {
+        $crate::ResultFn::new($f)
+    };
+    ($f:expr, $map:expr $(,)?) => {
+        $crate::ResultFn::with_mapping($f, $map)
+    };
+}
@@ -1,3 +1,5 @@
+use crate::ReshapeParam;
+
 /// Type-level marker trait for function safety, either [`Safe`] or [`Unsafe`].
 pub trait Safety {
     /// `true` for safe functions, `false` for unsafe ones.
@@ -18,6 +20,13 @@ impl Safety for Unsafe {
     const IS_SAFE: bool = false;
 }
 
+impl<T> ReshapeParam<T> for Safe {
+    type Out = Safe;
+}
+impl<T> ReshapeParam<T> for Unsafe {
+    type Out = Unsafe;
+}
+
 /// Macro to convert a safety token (`safe` or `unsafe`) or a boolean literal to the corresponding [`Safety`] marker type.
 #[macro_export]
 macro_rules! safety {
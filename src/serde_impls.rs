@@ -0,0 +1,41 @@
+use core::fmt;
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Unexpected, Visitor},
+};
+
+use crate::AbiValue;
+
+impl Serialize for AbiValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.to_str())
+    }
+}
+
+/// Visitor parsing an [`AbiValue`] from its string form, optionally restricted to a single value.
+pub(crate) struct AbiVisitor(pub(crate) Option<AbiValue>);
+
+impl Visitor<'_> for AbiVisitor {
+    type Value = AbiValue;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(abi) => write!(f, "the abi string \"{abi}\""),
+            None => f.write_str("an abi string like \"C\" or \"system-unwind\""),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        match v.parse::<AbiValue>() {
+            Ok(abi) if self.0.is_none_or(|expected| expected == abi) => Ok(abi),
+            _ => Err(E::invalid_value(Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AbiValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(AbiVisitor(None))
+    }
+}
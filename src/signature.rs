@@ -0,0 +1,70 @@
+use core::fmt::{self, Display};
+
+use crate::{AbiValue, FnPtr};
+
+/// Summary of everything known about a function pointer type except its concrete argument and
+/// return types.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{AbiValue, FnPtr, Signature};
+/// type F = unsafe extern "C" fn(i32, u8) -> bool;
+///
+/// let sig = F::signature();
+/// assert_eq!(sig, Signature { arity: 2, abi: AbiValue::C { unwind: false }, is_safe: false });
+/// assert!(sig.matches::<unsafe extern "C" fn(f32, f32)>());
+/// assert_eq!(sig.to_string(), r#"unsafe extern "C" fn(_, _)"#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Signature {
+    /// The number of arguments (see [`FnPtr::ARITY`]).
+    pub arity: usize,
+    /// The abi (see [`FnPtr::ABI`]).
+    pub abi: AbiValue,
+    /// Whether the function is safe to call (see [`FnPtr::IS_SAFE`]).
+    pub is_safe: bool,
+}
+
+impl Signature {
+    /// Returns the signature of the function pointer type `F`.
+    #[must_use]
+    pub const fn of<F: FnPtr>() -> Self {
+        Self {
+            arity: F::ARITY,
+            abi: F::ABI,
+            is_safe: F::IS_SAFE,
+        }
+    }
+
+    /// Returns `true` if the abi is an extern calling convention, i.e. not `Rust` (see [`FnPtr::IS_EXTERN`]).
+    #[must_use]
+    pub const fn is_extern(&self) -> bool {
+        !matches!(self.abi, AbiValue::Rust)
+    }
+
+    /// Returns `true` if this is the signature of the function pointer type `F`.
+    #[must_use]
+    pub fn matches<F: FnPtr>(&self) -> bool {
+        *self == Self::of::<F>()
+    }
+}
+
+impl Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_safe {
+            f.write_str("unsafe ")?;
+        }
+        if self.is_extern() {
+            write!(f, "{} ", self.abi.to_extern_clause())?;
+        }
+        f.write_str("fn(")?;
+        for i in 0..self.arity {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str("_")?;
+        }
+        f.write_str(")")
+    }
+}
@@ -0,0 +1,33 @@
+/// Asserts that `transformed` points to the same address as `original` and has the same arity.
+///
+/// All conversions of this crate (e.g. [`FnPtr::cast`](crate::FnPtr::cast), [`FnPtr::as_unsafe`](crate::FnPtr::as_unsafe)
+/// or [`FnPtr::with_abi`](crate::FnPtr::with_abi)) preserve the address of a function pointer.
+///
+/// Only available with the `test-util` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{FnPtr, assert_fn_preserves_addr};
+/// extern "C" fn add(a: i32, b: i32) -> i32 { a + b }
+///
+/// let f: extern "C" fn(i32, i32) -> i32 = add;
+/// assert_fn_preserves_addr!(f, f.as_unsafe());
+/// ```
+#[macro_export]
+macro_rules! assert_fn_preserves_addr {
+    ($original:expr, $transformed:expr $(,)?) => {{
+        let original = &$original;
+        let transformed = &$transformed;
+        ::core::assert_eq!(
+            $crate::FnPtr::addr(original),
+            $crate::FnPtr::addr(transformed),
+            "function pointer address was not preserved"
+        );
+        ::core::assert_eq!(
+            $crate::__private::arity_of(original),
+            $crate::__private::arity_of(transformed),
+            "function pointer arity was not preserved"
+        );
+    }};
+}
@@ -0,0 +1,189 @@
+use core::{any::TypeId, cell::RefCell, fmt, marker::PhantomData};
+use std::{any::Any, boxed::Box, collections::HashMap, rc::Rc, thread_local};
+
+use crate::FnPtr;
+
+type Closure<F> = RefCell<Box<dyn FnMut(<F as FnPtr>::Args) -> <F as FnPtr>::Output>>;
+
+thread_local! {
+    static SLOTS: RefCell<HashMap<TypeId, Rc<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// A function pointer type for which [`ClosureTrampoline`] can generate a trampoline.
+///
+/// Implemented for all safe and unsafe `extern "C"` and `extern "C-unwind"` function pointers with
+/// `'static` argument and return types.
+pub trait TrampolineTarget: FnPtr<Args: 'static, Output: 'static> + 'static {
+    #[doc(hidden)]
+    // Returns the trampoline dispatching to the closure in the slot `Tag`.
+    fn trampoline<Tag: 'static>() -> Self;
+}
+
+/// Dispatches a trampoline call to the closure registered in the `(F, Tag)` slot of this thread.
+fn dispatch<F: TrampolineTarget, Tag: 'static>(args: F::Args) -> F::Output {
+    // the closure is kept alive by this clone even if its guard is dropped during the call.
+    let closure = SLOTS
+        .with_borrow(|slots| slots.get(&TypeId::of::<(F, Tag)>()).cloned())
+        .expect("no closure registered for this trampoline on the current thread");
+    let closure = closure
+        .downcast_ref::<Closure<F>>()
+        .expect("slot holds a closure of a different type");
+    (closure.borrow_mut())(args)
+}
+
+/// Hands out non-capturing function pointers of type `F` which dispatch to a closure, for C apis
+/// whose callbacks do not take a userdata pointer.
+///
+/// The closure is stored in a thread-local slot identified by `F` and the marker type `Tag`.
+/// Each slot holds at most one closure at a time, so a separate `Tag` has to be used for every
+/// closure that should be registered for the same function pointer type concurrently.
+///
+/// Only available with the `std` feature.
+///
+/// # Limitations
+/// - The returned function pointer dispatches to the closure registered **on the calling thread**.
+///   Calling it on another thread or after the guard has been dropped panics.
+/// - Calling the function pointer from within the closure itself panics, as the closure is
+///   mutably borrowed during the call.
+///
+/// For a non-unwinding `F` (e.g. `extern "C"`) these panics abort the process.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::ClosureTrampoline;
+/// // stand-in for a c api taking a callback without userdata
+/// fn for_each(values: &[i32], callback: extern "C" fn(i32)) {
+///     for &value in values {
+///         callback(value);
+///     }
+/// }
+///
+/// let sum = std::rc::Rc::new(std::cell::Cell::new(0));
+/// let sum2 = sum.clone();
+/// let (callback, guard) = ClosureTrampoline::<extern "C" fn(i32)>::register(move |(value,)| {
+///     sum2.set(sum2.get() + value);
+/// });
+/// for_each(&[1, 2, 3], callback);
+/// drop(guard);
+/// assert_eq!(sum.get(), 6);
+/// ```
+pub struct ClosureTrampoline<F: TrampolineTarget, Tag: 'static = ()>(PhantomData<(F, Tag)>);
+
+impl<F: TrampolineTarget, Tag: 'static> ClosureTrampoline<F, Tag> {
+    /// Stores `closure` in the slot of `F` and `Tag` on the current thread and returns the trampoline
+    /// dispatching to it together with a guard unregistering the closure when dropped.
+    ///
+    /// # Panics
+    /// Panics if a closure is already registered in this slot on the current thread.
+    pub fn register(
+        closure: impl FnMut(F::Args) -> F::Output + 'static,
+    ) -> (F, TrampolineGuard<F, Tag>) {
+        let closure: Rc<Closure<F>> = Rc::new(RefCell::new(Box::new(closure)));
+        SLOTS.with_borrow_mut(|slots| {
+            let previous = slots.insert(TypeId::of::<(F, Tag)>(), closure);
+            assert!(
+                previous.is_none(),
+                "a closure is already registered for this trampoline slot"
+            );
+        });
+        (
+            F::trampoline::<Tag>(),
+            TrampolineGuard {
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    /// Returns whether a closure is registered in the slot of `F` and `Tag` on the current thread.
+    #[must_use]
+    pub fn is_registered() -> bool {
+        SLOTS.with_borrow(|slots| slots.contains_key(&TypeId::of::<(F, Tag)>()))
+    }
+}
+
+/// Guard returned by [`ClosureTrampoline::register`] unregistering the closure when dropped.
+#[must_use = "dropping the guard immediately unregisters the closure"]
+pub struct TrampolineGuard<F: TrampolineTarget, Tag: 'static = ()> {
+    // not `Send`, as the closure is registered in a thread-local slot.
+    _marker: PhantomData<(F, Tag, *const ())>,
+}
+
+impl<F: TrampolineTarget, Tag: 'static> Drop for TrampolineGuard<F, Tag> {
+    fn drop(&mut self) {
+        // the closure is dropped outside of the borrow, as its drop glue may access the slots.
+        let closure = SLOTS.with_borrow_mut(|slots| slots.remove(&TypeId::of::<(F, Tag)>()));
+        drop(closure);
+    }
+}
+
+impl<F: TrampolineTarget, Tag: 'static> fmt::Debug for TrampolineGuard<F, Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrampolineGuard").finish_non_exhaustive()
+    }
+}
+
+/// Internal helper macro to generate `TrampolineTarget` implementations.
+macro_rules! impl_trampoline_target {
+    ($($T:ident),*) => {
+        impl_trampoline_target!(@abi "C", $($T),*);
+        impl_trampoline_target!(@abi "C-unwind", $($T),*);
+    };
+    (@abi $abi:literal, $($T:ident),*) => {
+        impl<$($T: 'static,)* R: 'static> TrampolineTarget for extern $abi fn($($T),*) -> R {
+            fn trampoline<Tag: 'static>() -> Self {
+                impl_trampoline_target!(@trampoline $abi, $($T),*);
+                trampoline::<Self, Tag, $($T,)* R>
+            }
+        }
+
+        impl<$($T: 'static,)* R: 'static> TrampolineTarget for unsafe extern $abi fn($($T),*) -> R {
+            fn trampoline<Tag: 'static>() -> Self {
+                impl_trampoline_target!(@trampoline $abi, $($T),*);
+                trampoline::<Self, Tag, $($T,)* R>
+            }
+        }
+    };
+    (@trampoline $abi:literal, $($T:ident),*) => {
+        #[allow(non_snake_case)]
+        extern $abi fn trampoline<F, Tag, $($T: 'static,)* R: 'static>($($T: $T),*) -> R
+        where
+            F: TrampolineTarget<Args = ($($T,)*), Output = R>,
+            Tag: 'static,
+        {
+            dispatch::<F, Tag>(($($T,)*))
+        }
+    };
+}
+
+impl_trampoline_target!();
+impl_trampoline_target!(T1);
+impl_trampoline_target!(T1, T2);
+impl_trampoline_target!(T1, T2, T3);
+impl_trampoline_target!(T1, T2, T3, T4);
+impl_trampoline_target!(T1, T2, T3, T4, T5);
+impl_trampoline_target!(T1, T2, T3, T4, T5, T6);
+#[cfg(has_arity_7)]
+impl_trampoline_target!(T1, T2, T3, T4, T5, T6, T7);
+#[cfg(has_arity_8)]
+impl_trampoline_target!(T1, T2, T3, T4, T5, T6, T7, T8);
+#[cfg(has_arity_9)]
+impl_trampoline_target!(T1, T2, T3, T4, T5, T6, T7, T8, T9);
+#[cfg(has_arity_10)]
+impl_trampoline_target!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+#[cfg(has_arity_11)]
+impl_trampoline_target!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+#[cfg(has_arity_12)]
+impl_trampoline_target!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+#[cfg(has_arity_13)]
+impl_trampoline_target!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13);
+#[cfg(has_arity_14)]
+impl_trampoline_target!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14);
+#[cfg(has_arity_15)]
+impl_trampoline_target!(
+    T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15
+);
+#[cfg(has_arity_16)]
+impl_trampoline_target!(
+    T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16
+);
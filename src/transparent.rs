@@ -0,0 +1,81 @@
+use crate::Tuple;
+
+/// Marker trait for types which are `#[repr(transparent)]` wrappers around `T`, so that a function
+/// taking a `T` can soundly be called with `Self` instead.
+///
+/// Implemented for `T` itself. Newtypes should implement it through [`transparent_arg!`](crate::transparent_arg),
+/// which also applies `#[repr(transparent)]`.
+///
+/// # Safety
+/// `Self` must be `#[repr(transparent)]` over `T` (or `T` itself).
+pub unsafe trait TransparentArg<T> {}
+
+unsafe impl<T> TransparentArg<T> for T {}
+
+/// Marker trait for argument tuples whose elements are [`TransparentArg`]s of the elements of `Self`,
+/// used by [`FnPtr::cast_arg_transparent`](crate::FnPtr::cast_arg_transparent).
+///
+/// # Safety
+/// Every element of `Target` must be [`TransparentArg`] over the corresponding element of `Self`.
+pub unsafe trait TransparentArgs<Target: Tuple>: Tuple {}
+
+macro_rules! impl_transparent_args {
+    ($($T:ident => $U:ident),*) => {
+        unsafe impl<$($T,)* $($U: TransparentArg<$T>,)*> TransparentArgs<($($U,)*)> for ($($T,)*) {}
+    };
+}
+
+impl_transparent_args!();
+impl_transparent_args!(T1 => U1);
+impl_transparent_args!(T1 => U1, T2 => U2);
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3);
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3, T4 => U4);
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3, T4 => U4, T5 => U5);
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3, T4 => U4, T5 => U5, T6 => U6);
+#[cfg(has_arity_7)]
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3, T4 => U4, T5 => U5, T6 => U6, T7 => U7);
+#[cfg(has_arity_8)]
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3, T4 => U4, T5 => U5, T6 => U6, T7 => U7, T8 => U8);
+#[cfg(has_arity_9)]
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3, T4 => U4, T5 => U5, T6 => U6, T7 => U7, T8 => U8, T9 => U9);
+#[cfg(has_arity_10)]
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3, T4 => U4, T5 => U5, T6 => U6, T7 => U7, T8 => U8, T9 => U9, T10 => U10);
+#[cfg(has_arity_11)]
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3, T4 => U4, T5 => U5, T6 => U6, T7 => U7, T8 => U8, T9 => U9, T10 => U10, T11 => U11);
+#[cfg(has_arity_12)]
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3, T4 => U4, T5 => U5, T6 => U6, T7 => U7, T8 => U8, T9 => U9, T10 => U10, T11 => U11, T12 => U12);
+#[cfg(has_arity_13)]
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3, T4 => U4, T5 => U5, T6 => U6, T7 => U7, T8 => U8, T9 => U9, T10 => U10, T11 => U11, T12 => U12, T13 => U13);
+#[cfg(has_arity_14)]
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3, T4 => U4, T5 => U5, T6 => U6, T7 => U7, T8 => U8, T9 => U9, T10 => U10, T11 => U11, T12 => U12, T13 => U13, T14 => U14);
+#[cfg(has_arity_15)]
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3, T4 => U4, T5 => U5, T6 => U6, T7 => U7, T8 => U8, T9 => U9, T10 => U10, T11 => U11, T12 => U12, T13 => U13, T14 => U14, T15 => U15);
+#[cfg(has_arity_16)]
+impl_transparent_args!(T1 => U1, T2 => U2, T3 => U3, T4 => U4, T5 => U5, T6 => U6, T7 => U7, T8 => U8, T9 => U9, T10 => U10, T11 => U11, T12 => U12, T13 => U13, T14 => U14, T15 => U15, T16 => U16);
+
+/// Declares a `#[repr(transparent)]` newtype and implements [`TransparentArg`] for it, so function
+/// pointers taking the inner type can be cast to take the newtype with [`FnPtr::cast_arg_transparent`](crate::FnPtr::cast_arg_transparent).
+///
+/// The `#[repr(transparent)]` attribute is added by the macro and should not be given explicitly.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{TransparentArg, transparent_arg};
+/// transparent_arg! {
+///     #[derive(Debug)]
+///     pub struct Fd(pub i32);
+/// }
+/// # static_assertions::assert_impl_all!(Fd: TransparentArg<i32>);
+/// ```
+#[macro_export]
+macro_rules! transparent_arg {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident($field_vis:vis $inner:ty);) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        $vis struct $name($field_vis $inner);
+
+        // SAFETY: the newtype is `#[repr(transparent)]` over its only field.
+        unsafe impl $crate::TransparentArg<$inner> for $name {}
+    };
+}
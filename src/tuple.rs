@@ -1,9 +1,8 @@
-use crate::arity::{self, A0, A1, A2, A3, A4, A5, A6};
-#[cfg(feature = "max-arity-12")]
-use crate::arity::{A7, A8, A9, A10, A11, A12};
+use crate::arity;
 
 cfg_tt::cfg_tt! {
-/// A trait implemented for all tuple types up to arity 6 (or 12 with feature `max-arity-12`).
+/// A trait implemented for all tuple types up to arity 6 (or 12 with feature `max-arity-12`, or the
+/// value of the `FN_PTR_MAX_ARITY` environment variable at build time, up to 16).
 pub trait Tuple
     #[cfg(nightly_build)]
     (: core::marker::Tuple) {
@@ -18,44 +17,351 @@ pub trait Tuple
     #[doc(hidden)]
     // This is required for WithArgs
     type BaseFn: crate::FnPtr<Args = Self>;
+
+    /// An array with one `T` per element of this tuple, i.e. `[T; N]` for a tuple of arity `N`.
+    type Array<T>: AsRef<[T]> + AsMut<[T]> + IntoIterator<Item = T>;
+
+    #[doc(hidden)]
+    // Returns the `TypeId` of each element in order, used by `StaticFnPtr::arg_type_ids`.
+    fn type_ids() -> Self::Array<core::any::TypeId>
+    where
+        Self: 'static;
+}
+}
+
+/// A [`Tuple`] whose elements are all [`Clone`], allowing it to be cloned from a tuple of references.
+pub trait CloneFromRefs: Tuple {
+    /// The tuple of references to the elements of this tuple, e.g. `(&'a T, &'a U)` for `(T, U)`.
+    type Refs<'a>
+    where
+        Self: 'a;
+
+    /// Constructs this tuple by cloning each referenced element.
+    fn clone_from_refs(refs: Self::Refs<'_>) -> Self;
+}
+
+/// A non-empty [`Tuple`] which can be split into its first element and the remaining elements.
+pub trait SplitFirst: Tuple {
+    /// The type of the first element.
+    type First;
+    /// The tuple of the remaining elements, e.g. `(U, V)` for `(T, U, V)`.
+    type Rest: Tuple;
+
+    /// Splits this tuple into its first element and the remaining elements.
+    fn split_first(self) -> (Self::First, Self::Rest);
+
+    /// Constructs this tuple from its first element and the remaining elements.
+    fn join_first(first: Self::First, rest: Self::Rest) -> Self;
+}
+
+/// A non-empty [`Tuple`] which can be split into its leading elements and its last element.
+pub trait SplitLast: Tuple {
+    /// The tuple of the leading elements, e.g. `(T, U)` for `(T, U, V)`.
+    type Init: Tuple;
+    /// The type of the last element.
+    type Last;
+
+    /// Splits this tuple into its leading elements and its last element.
+    fn split_last(self) -> (Self::Init, Self::Last);
+
+    /// Constructs this tuple from its leading elements and its last element.
+    fn join_last(init: Self::Init, last: Self::Last) -> Self;
+}
+
+/// A [`Tuple`] with an element at index `I`.
+///
+/// Implemented for all `I` less than the arity of the tuple.
+pub trait Element<const I: usize>: Tuple {
+    /// The type of the element at index `I`, e.g. `U` for `(T, U, V)` and `I = 1`.
+    type Type;
+}
+
+/// A [`Tuple`] whose element at index `I` can be replaced with a `T`.
+///
+/// Implemented for all `I` less than the arity of the tuple.
+pub trait ReplaceElement<const I: usize, T>: Tuple {
+    /// The tuple with the element at index `I` replaced, e.g. `(T, X, V)` for `(T, U, V)`, `I = 1` and `X`.
+    type Output: Tuple;
+}
+
+/// A [`Tuple`] which can be concatenated with the tuple `Rhs`.
+///
+/// Implemented for all pairs of tuples whose combined arity does not exceed the maximum arity.
+pub trait Concat<Rhs: Tuple>: Tuple {
+    /// The concatenated tuple, e.g. `(T, U, V)` for `(T,)` and `(U, V)`.
+    type Output: Tuple;
+
+    /// Concatenates this tuple with `rhs`.
+    fn concat(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A [`Tuple`] of tuples which can be flattened by one level.
+///
+/// Every element has to be a tuple itself; non-tuple elements have to be wrapped in a singleton
+/// tuple, e.g. `((i32, u8), (f32,))` flattens to `(i32, u8, f32)`. Nested tuples inside the elements
+/// are left untouched, so `(((i32, u8),),)` flattens to `((i32, u8),)`.
+pub trait Flatten: Tuple {
+    /// The flattened tuple.
+    type Output: Tuple;
+
+    /// Flattens this tuple by one level.
+    fn flatten(self) -> Self::Output;
+}
+
+impl Flatten for () {
+    type Output = ();
+
+    fn flatten(self) -> Self::Output {}
+}
+
+impl<T: SplitFirst> Flatten for T
+where
+    T::Rest: Flatten,
+    T::First: Concat<<T::Rest as Flatten>::Output>,
+{
+    type Output = <T::First as Concat<<T::Rest as Flatten>::Output>>::Output;
+
+    fn flatten(self) -> Self::Output {
+        let (first, rest) = self.split_first();
+        first.concat(rest.flatten())
+    }
+}
+
+/// A [`Tuple`] which can be reversed, e.g. `(T, U, V)` to `(V, U, T)`.
+pub trait Reverse: Tuple {
+    /// The reversed tuple.
+    type Output: Tuple;
+
+    /// Reverses the order of the elements of this tuple.
+    fn reverse(self) -> Self::Output;
+}
+
+impl Reverse for () {
+    type Output = ();
+
+    fn reverse(self) -> Self::Output {}
+}
+
+impl<T: SplitFirst> Reverse for T
+where
+    T::Rest: Reverse<Output: Concat<(T::First,)>>,
+{
+    type Output = <<T::Rest as Reverse>::Output as Concat<(T::First,)>>::Output;
+
+    fn reverse(self) -> Self::Output {
+        let (first, rest) = self.split_first();
+        rest.reverse().concat((first,))
+    }
 }
+
+/// Internal helper macro to generate `Concat` implementations for all splits of the tuple `($T..)`.
+macro_rules! impl_concat {
+    ($($T:ident),*) => {
+        impl_concat!(@split () ($($T),*));
+    };
+
+    (@split ($($T:ident),*) ()) => {
+        impl_concat!(@impl ($($T),*) ());
+    };
+    (@split ($($T:ident),*) ($next:ident $(, $U:ident)*)) => {
+        impl_concat!(@impl ($($T),*) ($next $(, $U)*));
+        impl_concat!(@split ($($T,)* $next) ($($U),*));
+    };
+
+    (@impl ($($T:ident),*) ($($U:ident),*)) => {
+        impl<$($T,)* $($U,)*> Concat<($($U,)*)> for ($($T,)*) {
+            type Output = ($($T,)* $($U,)*);
+
+            #[allow(non_snake_case, clippy::unused_unit)]
+            fn concat(self, rhs: ($($U,)*)) -> Self::Output {
+                let ($($T,)*) = self;
+                let ($($U,)*) = rhs;
+                ($($T,)* $($U,)*)
+            }
+        }
+    };
 }
 
+impl_concat!();
+impl_concat!(T1);
+impl_concat!(T1, T2);
+impl_concat!(T1, T2, T3);
+impl_concat!(T1, T2, T3, T4);
+impl_concat!(T1, T2, T3, T4, T5);
+impl_concat!(T1, T2, T3, T4, T5, T6);
+#[cfg(has_arity_7)]
+impl_concat!(T1, T2, T3, T4, T5, T6, T7);
+#[cfg(has_arity_8)]
+impl_concat!(T1, T2, T3, T4, T5, T6, T7, T8);
+#[cfg(has_arity_9)]
+impl_concat!(T1, T2, T3, T4, T5, T6, T7, T8, T9);
+#[cfg(has_arity_10)]
+impl_concat!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+#[cfg(has_arity_11)]
+impl_concat!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+#[cfg(has_arity_12)]
+impl_concat!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+#[cfg(has_arity_13)]
+impl_concat!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13);
+#[cfg(has_arity_14)]
+impl_concat!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14);
+#[cfg(has_arity_15)]
+impl_concat!(
+    T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15
+);
+#[cfg(has_arity_16)]
+impl_concat!(
+    T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16
+);
+
 /// Internal helper macro to generate `Tuple` implementations.
 macro_rules! impl_tuple {
     // arity 0
-    (0, $arity:ty) => {
+    (0) => {
         impl Tuple for () {
-            type Arity = $arity;
+            type Arity = arity::MarkerFor<0>;
             type BaseFn = fn();
+            type Array<T> = [T; 0];
+
+            fn type_ids() -> Self::Array<core::any::TypeId> {
+                []
+            }
+        }
+
+        impl<T> crate::ReshapeParam<T> for () {
+            type Out = ();
+        }
+
+        impl CloneFromRefs for () {
+            type Refs<'a> = ();
+
+            fn clone_from_refs((): Self::Refs<'_>) -> Self {}
         }
     };
 
     // arity N >= 1
-    ($n:tt, $arity:ty, ( $($T:ident),+ )) => {
+    ($n:tt, ( $($T:ident),+ )) => {
         impl< $($T),+ > Tuple for ( $($T,)+ ) {
-            type Arity = $arity;
+            type Arity = arity::MarkerFor<$n>;
             type BaseFn = fn($($T,)+);
+            type Array<T> = [T; $n];
+
+            fn type_ids() -> Self::Array<core::any::TypeId>
+            where
+                Self: 'static,
+            {
+                [$(core::any::TypeId::of::<$T>()),+]
+            }
+        }
+
+        impl<T, $($T),+ > crate::ReshapeParam<T> for ( $($T,)+ ) {
+            type Out = ( $($T,)+ );
+        }
+
+        impl< $($T: Clone),+ > CloneFromRefs for ( $($T,)+ ) {
+            type Refs<'a> = ( $(&'a $T,)+ ) where Self: 'a;
+
+            #[allow(non_snake_case)]
+            fn clone_from_refs(( $($T,)+ ): Self::Refs<'_>) -> Self {
+                ( $($T.clone(),)+ )
+            }
+        }
+
+        impl_tuple!(@split_first $($T),+);
+        impl_tuple!(@split_last [] $($T),+);
+        impl_tuple!(@elements [] [$($T),+] [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15]);
+    };
+
+    (@elements [$($Before:ident),*] [$Cur:ident $(, $After:ident)*] [$i:tt $($is:tt)*]) => {
+        impl<$($Before,)* $Cur, $($After,)*> Element<$i> for ($($Before,)* $Cur, $($After,)*) {
+            type Type = $Cur;
+        }
+
+        impl<T, $($Before,)* $Cur, $($After,)*> ReplaceElement<$i, T> for ($($Before,)* $Cur, $($After,)*) {
+            type Output = ($($Before,)* T, $($After,)*);
+        }
+
+        impl_tuple!(@elements [$($Before,)* $Cur] [$($After),*] [$($is)*]);
+    };
+    (@elements [$($Before:ident),*] [] [$($is:tt)*]) => {};
+
+    (@split_last [$($Init:ident),*] $Next:ident, $($Rest:ident),+) => {
+        impl_tuple!(@split_last [$($Init,)* $Next] $($Rest),+);
+    };
+    (@split_last [$($Init:ident),*] $Last:ident) => {
+        impl<$($Init,)* $Last> SplitLast for ($($Init,)* $Last,) {
+            type Init = ($($Init,)*);
+            type Last = $Last;
+
+            #[allow(non_snake_case)]
+            fn split_last(self) -> (Self::Init, Self::Last) {
+                let ($($Init,)* $Last,) = self;
+                (($($Init,)*), $Last)
+            }
+
+            #[allow(non_snake_case)]
+            fn join_last(($($Init,)*): Self::Init, last: Self::Last) -> Self {
+                ($($Init,)* last,)
+            }
+        }
+    };
+
+    (@split_first $First:ident $(, $Rest:ident)*) => {
+        impl<$First, $($Rest),*> SplitFirst for ($First, $($Rest,)*) {
+            type First = $First;
+            type Rest = ($($Rest,)*);
+
+            #[allow(non_snake_case)]
+            fn split_first(self) -> (Self::First, Self::Rest) {
+                let ($First, $($Rest,)*) = self;
+                ($First, ($($Rest,)*))
+            }
+
+            #[allow(non_snake_case)]
+            fn join_first(first: Self::First, ($($Rest,)*): Self::Rest) -> Self {
+                (first, $($Rest,)*)
+            }
         }
     };
 }
 
-impl_tuple!(0, A0);
-impl_tuple!(1, A1, (T1));
-impl_tuple!(2, A2, (T1, T2));
-impl_tuple!(3, A3, (T1, T2, T3));
-impl_tuple!(4, A4, (T1, T2, T3, T4));
-impl_tuple!(5, A5, (T1, T2, T3, T4, T5));
-impl_tuple!(6, A6, (T1, T2, T3, T4, T5, T6));
-#[cfg(feature = "max-arity-12")]
-impl_tuple!(7, A7, (T1, T2, T3, T4, T5, T6, T7));
-#[cfg(feature = "max-arity-12")]
-impl_tuple!(8, A8, (T1, T2, T3, T4, T5, T6, T7, T8));
-#[cfg(feature = "max-arity-12")]
-impl_tuple!(9, A9, (T1, T2, T3, T4, T5, T6, T7, T8, T9));
-#[cfg(feature = "max-arity-12")]
-impl_tuple!(10, A10, (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10));
-#[cfg(feature = "max-arity-12")]
-impl_tuple!(11, A11, (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11));
-#[cfg(feature = "max-arity-12")]
-impl_tuple!(12, A12, (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12));
+impl_tuple!(0);
+impl_tuple!(1, (T1));
+impl_tuple!(2, (T1, T2));
+impl_tuple!(3, (T1, T2, T3));
+impl_tuple!(4, (T1, T2, T3, T4));
+impl_tuple!(5, (T1, T2, T3, T4, T5));
+impl_tuple!(6, (T1, T2, T3, T4, T5, T6));
+#[cfg(has_arity_7)]
+impl_tuple!(7, (T1, T2, T3, T4, T5, T6, T7));
+#[cfg(has_arity_8)]
+impl_tuple!(8, (T1, T2, T3, T4, T5, T6, T7, T8));
+#[cfg(has_arity_9)]
+impl_tuple!(9, (T1, T2, T3, T4, T5, T6, T7, T8, T9));
+#[cfg(has_arity_10)]
+impl_tuple!(10, (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10));
+#[cfg(has_arity_11)]
+impl_tuple!(11, (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11));
+#[cfg(has_arity_12)]
+impl_tuple!(12, (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12));
+#[cfg(has_arity_13)]
+impl_tuple!(13, (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13));
+#[cfg(has_arity_14)]
+impl_tuple!(
+    14,
+    (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14)
+);
+#[cfg(has_arity_15)]
+impl_tuple!(
+    15,
+    (
+        T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15
+    )
+);
+#[cfg(has_arity_16)]
+impl_tuple!(
+    16,
+    (
+        T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16
+    )
+);
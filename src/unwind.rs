@@ -0,0 +1,194 @@
+use crate::{FnPtr, SafeFnPtr, abi::UnwindCompatible};
+
+/// Marker trait for function pointer types which can replace a function of type `Target` without
+/// unwinding across a boundary that does not allow it.
+///
+/// Implemented for any [`FnPtr`] if `Target` allows unwinding or the replacement itself does not
+/// allow unwinding (in which case a panic aborts), and for any [`AbortOnPanic`] wrapper.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{AbortOnPanic, SafeReplacement};
+/// fn install<Target, R: SafeReplacement<Target>>(_replacement: R) {}
+///
+/// type Target = extern "C" fn(i32) -> i32;
+/// extern "C" fn c_hook(x: i32) -> i32 { x }
+/// fn rust_hook(x: i32) -> i32 { x }
+///
+/// install::<Target, _>(c_hook as extern "C" fn(i32) -> i32);
+/// install::<Target, _>(AbortOnPanic(rust_hook as fn(i32) -> i32));
+/// // install::<Target, _>(rust_hook as fn(i32) -> i32); // does not compile
+/// ```
+pub trait SafeReplacement<Target> {}
+
+impl<Target: FnPtr, F: FnPtr> SafeReplacement<Target> for F where
+    Target::Abi: UnwindCompatible<F::Abi>
+{
+}
+
+impl<Target: FnPtr, F: FnPtr> SafeReplacement<Target> for AbortOnPanic<F> {}
+
+/// Wrapper around a function pointer which aborts the process if the function panics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct AbortOnPanic<F: FnPtr>(pub F);
+
+impl<F: SafeFnPtr> AbortOnPanic<F> {
+    /// Invokes the wrapped function, aborting the process if it panics.
+    pub fn invoke(&self, args: F::Args) -> F::Output {
+        let guard = AbortOnDrop;
+        let output = self.0.invoke(args);
+        core::mem::forget(guard);
+        output
+    }
+}
+
+/// Wrapper around a function pointer which catches panics and converts them into a return value,
+/// e.g. an error code for a C api.
+///
+/// Only available with the `std` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::PanicToCode;
+/// # use std::any::Any;
+/// fn handler(value: i32) -> i32 {
+///     assert!(value >= 0, "negative value");
+///     0
+/// }
+///
+/// static HANDLER: PanicToCode<fn(i32) -> i32, fn(Box<dyn Any + Send>) -> i32> =
+///     PanicToCode::new(handler, |_| -1);
+///
+/// // callback slot of a C api, which must not unwind
+/// extern "C" fn callback(value: i32) -> i32 {
+///     HANDLER.invoke((value,))
+/// }
+///
+/// assert_eq!(callback(1), 0);
+/// # std::panic::set_hook(Box::new(|_| {}));
+/// assert_eq!(callback(-1), -1);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PanicToCode<F, M> {
+    /// The wrapped function pointer.
+    pub f: F,
+    /// Maps the payload of a caught panic to the return value.
+    pub on_panic: M,
+}
+
+#[cfg(feature = "std")]
+impl<F: FnPtr, M> PanicToCode<F, M>
+where
+    M: Fn(std::boxed::Box<dyn core::any::Any + Send>) -> F::Output,
+{
+    /// Wraps `f`, mapping panics with `on_panic`.
+    pub const fn new(f: F, on_panic: M) -> Self {
+        Self { f, on_panic }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F: SafeFnPtr, M> PanicToCode<F, M>
+where
+    M: Fn(std::boxed::Box<dyn core::any::Any + Send>) -> F::Output,
+{
+    /// Invokes the wrapped function, returning the result of [`on_panic`](PanicToCode::on_panic) if it panics.
+    pub fn invoke(&self, args: F::Args) -> F::Output {
+        // the args are moved into the call and dropped if it panics.
+        std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| self.f.invoke(args)))
+            .unwrap_or_else(&self.on_panic)
+    }
+}
+
+/// Guard which panics when dropped, which aborts if dropped while unwinding.
+struct AbortOnDrop;
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        panic!("panic in function wrapped in AbortOnPanic");
+    }
+}
+
+/// Generates an `extern "C"` thunk calling the given Rust function and evaluates to its function pointer.
+///
+/// The signature of the function has to be given explicitly, e.g. `c_thunk!(handler::<u32> as fn(u32) -> u32)`.
+/// The call is wrapped in [`AbortOnPanic`], so a panic in the function aborts instead of unwinding into C code.
+///
+/// The thunk is a nested function item, so the signature must not refer to generic parameters of the
+/// surrounding function.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::c_thunk;
+/// fn handler<T: Into<u64>>(value: T) -> u64 {
+///     value.into() * 2
+/// }
+///
+/// let f: extern "C" fn(u32) -> u64 = c_thunk!(handler::<u32> as fn(u32) -> u64);
+/// assert_eq!(f(21), 42);
+/// ```
+#[macro_export]
+macro_rules! c_thunk {
+    ($f:path as fn($($arg:ty),* $(,)?) $(-> $ret:ty)?) => {
+        $crate::c_thunk!(
+            @zip $f, ($($ret)?), [], [$($arg),*],
+            [
+                __arg0 __arg1 __arg2 __arg3 __arg4 __arg5 __arg6 __arg7
+                __arg8 __arg9 __arg10 __arg11 __arg12 __arg13 __arg14 __arg15
+            ]
+        )
+    };
+
+    // assign a parameter name to each argument type
+    (@zip $f:path, $ret:tt, [$($done:tt)*], [$ty:ty $(, $rest:ty)*], [$name:ident $($names:ident)*]) => {
+        $crate::c_thunk!(@zip $f, $ret, [$($done)* ($name: $ty)], [$($rest),*], [$($names)*])
+    };
+    (@zip $f:path, ($($ret:ty)?), [$(($name:ident: $ty:ty))*], [], [$($names:ident)*]) => {{
+        extern "C" fn thunk($($name: $ty),*) $(-> $ret)? {
+            $crate::AbortOnPanic($f as fn($($ty),*) $(-> $ret)?).invoke(($($name,)*))
+        }
+        thunk as extern "C" fn($($ty),*) $(-> $ret)?
+    }};
+}
+
+/// Asserts at compile time that converting a function pointer of type `From` to `To` does not remove
+/// the ability to unwind (see [`changes_unwind`](crate::changes_unwind)).
+///
+/// The assertion is evaluated in an inline `const` block, so it can be used with generic parameters
+/// and fails when the surrounding function is instantiated with a dangerous conversion.
+///
+/// # Example
+///
+/// ```rust
+/// # use fn_ptr::{FnPtr, assert_unwind_preserving};
+/// fn convert<From: FnPtr, To: FnPtr>(f: From) -> To {
+///     assert_unwind_preserving!(From, To);
+///     unsafe { f.cast() }
+/// }
+///
+/// fn noop() {}
+/// let f: extern "C-unwind" fn() = convert(noop as fn());
+/// ```
+///
+/// ```rust,compile_fail
+/// # use fn_ptr::assert_unwind_preserving;
+/// fn check() {
+///     assert_unwind_preserving!(fn(), extern "C" fn()); // error
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_unwind_preserving {
+    ($from:ty, $to:ty $(,)?) => {
+        const {
+            ::core::assert!(
+                !$crate::changes_unwind::<$from, $to>(),
+                "conversion removes the ability to unwind from an unwinding function pointer"
+            );
+        }
+    };
+}
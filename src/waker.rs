@@ -0,0 +1,67 @@
+/// Constructs a [`RawWakerVTable`](core::task::RawWakerVTable) from four function pointers.
+///
+/// The arguments are the `clone`, `wake`, `wake_by_ref` and `drop` functions of the vtable. Any [`FnPtr`](crate::FnPtr)
+/// with the `"Rust"` abi and the signature of the corresponding slot is accepted, regardless of its safety.
+/// Mismatched signatures are rejected at compile time. The macro can be used in `const` contexts.
+///
+/// # Example
+///
+/// ```rust
+/// # use core::task::{RawWaker, RawWakerVTable, Waker};
+/// # use fn_ptr::waker_vtable;
+/// fn clone(data: *const ()) -> RawWaker { RawWaker::new(data, &VTABLE) }
+/// fn noop(_: *const ()) {}
+///
+/// type Slot = fn(*const ());
+/// static VTABLE: RawWakerVTable = waker_vtable!(
+///     clone as fn(*const ()) -> RawWaker,
+///     noop as Slot,
+///     noop as Slot,
+///     noop as Slot,
+/// );
+///
+/// let waker = unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) };
+/// waker.wake_by_ref();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use core::task::{RawWaker, RawWakerVTable};
+/// # use fn_ptr::waker_vtable;
+/// fn clone(data: *const ()) -> RawWaker { RawWaker::new(data, &VTABLE) }
+/// fn noop(_: *const ()) {}
+/// fn wrong(_: *mut u8) {}
+///
+/// static VTABLE: RawWakerVTable = waker_vtable!(
+///     clone as fn(*const ()) -> RawWaker,
+///     noop as fn(*const ()),
+///     wrong as fn(*mut u8), // error: wrong argument type
+///     noop as fn(*const ()),
+/// );
+/// ```
+#[macro_export]
+macro_rules! waker_vtable {
+    ($clone:expr, $wake:expr, $wake_by_ref:expr, $drop:expr $(,)?) => {
+        ::core::task::RawWakerVTable::new(
+            $crate::__private::waker_slot::<_, ::core::task::RawWaker>($clone),
+            $crate::__private::waker_slot::<_, ()>($wake),
+            $crate::__private::waker_slot::<_, ()>($wake_by_ref),
+            $crate::__private::waker_slot::<_, ()>($drop),
+        )
+    };
+}
+
+/// Converts `f` into the type of a [`RawWakerVTable`](core::task::RawWakerVTable) slot returning `O`.
+#[must_use]
+pub const fn waker_slot<F, O>(f: F) -> unsafe fn(*const ()) -> O
+where
+    F: crate::FnPtr<Args = (*const (),), Output = O, Abi = crate::abi::Rust>,
+{
+    #[repr(C)]
+    union Transmute<F: Copy, O> {
+        f: F,
+        slot: unsafe fn(*const ()) -> O,
+    }
+
+    // SAFETY: `F` only differs from the slot type in safety, which can always be weakened to `unsafe`.
+    unsafe { Transmute { f }.slot }
+}
@@ -0,0 +1,298 @@
+use fn_ptr::{Abi, AbiValue, abi};
+
+#[test]
+fn cstr_round_trip() {
+    let abis = [
+        AbiValue::Rust,
+        AbiValue::C { unwind: false },
+        AbiValue::C { unwind: true },
+        AbiValue::System { unwind: true },
+        AbiValue::SysV64 { unwind: false },
+        AbiValue::EfiApi,
+    ];
+    for abi in abis {
+        let s = abi.as_cstr();
+        assert_eq!(s.to_str().unwrap(), abi.to_str());
+        assert_eq!(AbiValue::from_cstr(s), Some(abi));
+    }
+}
+
+#[test]
+fn from_cstr_unknown() {
+    assert_eq!(AbiValue::from_cstr(c"C-unwinds"), None);
+    assert_eq!(AbiValue::from_cstr(c""), None);
+    assert_eq!(AbiValue::from_cstr(c"\xff"), None);
+}
+
+#[test]
+fn index_round_trip() {
+    let mut index = 0;
+    while let Some(abi) = AbiValue::from_index(index) {
+        assert_eq!(abi.index(), index);
+        index += 1;
+    }
+    assert_eq!(index, 22);
+}
+
+#[test]
+fn markers_match_abi_values() {
+    macro_rules! check {
+        ($($name:ident),* $(,)?) => {{
+            let markers = [$(
+                (stringify!($name), <abi::$name as Abi>::VALUE, <abi::$name as Abi>::STR)
+            ),*];
+            for (name, value, s) in markers {
+                assert_eq!(abi::marker_str_of(value), name);
+                assert_eq!(value.to_str(), s);
+            }
+            markers.len()
+        }};
+    }
+
+    let count = check!(
+        Rust,
+        C,
+        CUnwind,
+        System,
+        SystemUnwind,
+        Aapcs,
+        AapcsUnwind,
+        Cdecl,
+        CdeclUnwind,
+        Stdcall,
+        StdcallUnwind,
+        Fastcall,
+        FastcallUnwind,
+        Thiscall,
+        ThiscallUnwind,
+        Vectorcall,
+        VectorcallUnwind,
+        SysV64,
+        SysV64Unwind,
+        Win64,
+        Win64Unwind,
+        EfiApi,
+    );
+
+    // every abi value has exactly one marker
+    let mut values = 0;
+    while let Some(value) = AbiValue::from_index(values) {
+        assert!(!abi::marker_str_of(value).is_empty());
+        values += 1;
+    }
+    assert_eq!(usize::from(values), count);
+}
+
+#[test]
+fn extern_clause_for_each_abi() {
+    let mut index = 0;
+    while let Some(abi) = AbiValue::from_index(index) {
+        let clause = abi.to_extern_clause();
+        assert_eq!(clause.abi(), abi);
+        assert_eq!(abi.to_string(), abi.to_str());
+        assert_eq!(
+            clause.explicit().to_string(),
+            format!("extern \"{}\"", abi.to_str())
+        );
+        if abi == AbiValue::Rust {
+            assert_eq!(clause.to_string(), "");
+        } else {
+            assert_eq!(clause.to_string(), clause.explicit().to_string());
+        }
+        index += 1;
+    }
+}
+
+#[test]
+fn supported_abi_strings_parse() {
+    let supported = fn_ptr::supported_abi_strings();
+    for s in supported {
+        let abi: AbiValue = s.parse().unwrap();
+        assert_eq!(abi.to_str(), *s);
+    }
+    for s in ["Rust", "C", "C-unwind", "system", "system-unwind"] {
+        assert!(supported.contains(&s));
+    }
+    if cfg!(all(target_arch = "x86_64", not(windows))) {
+        assert!(supported.contains(&"sysv64"));
+    }
+}
+
+#[test]
+fn rustc_names() {
+    // as emitted by rustc 1.95
+    let table = [
+        (AbiValue::Rust, "Rust"),
+        (AbiValue::C { unwind: false }, "C"),
+        (AbiValue::C { unwind: true }, "C-unwind"),
+        (AbiValue::System { unwind: true }, "system-unwind"),
+        (AbiValue::SysV64 { unwind: false }, "sysv64"),
+        (AbiValue::Win64 { unwind: true }, "win64-unwind"),
+        (AbiValue::Vectorcall { unwind: false }, "vectorcall"),
+        (AbiValue::EfiApi, "efiapi"),
+    ];
+    for (abi, name) in table {
+        assert_eq!(abi.to_rustc_name(), name);
+        assert_eq!(AbiValue::from_rustc_name(name), Some(abi));
+        assert_eq!(AbiValue::from_rustc_name(&format!("\"{name}\"")), Some(abi));
+    }
+    assert_eq!(AbiValue::from_rustc_name("\"C"), None);
+    assert_eq!(AbiValue::from_rustc_name("rust-call"), None);
+}
+
+#[test]
+fn eq_canonical() {
+    let c = AbiValue::C { unwind: false };
+    assert!(c.eq_canonical(c));
+    assert!(c.eq_canonical(AbiValue::Cdecl { unwind: false }));
+    assert!(!c.eq_canonical(AbiValue::C { unwind: true }));
+    assert!(!c.eq_canonical(AbiValue::Rust));
+
+    // abis which do not canonize on the current target are never equal.
+    #[cfg(not(target_arch = "x86"))]
+    {
+        let thiscall = AbiValue::Thiscall { unwind: false };
+        assert_eq!(thiscall.canonize(false), None);
+        assert!(!thiscall.eq_canonical(thiscall));
+        assert!(!thiscall.eq_canonical(AbiValue::Aapcs { unwind: false }));
+    }
+}
+
+#[test]
+fn canonize_c_with_varargs() {
+    for unwind in [false, true] {
+        let c = AbiValue::C { unwind };
+        assert_eq!(c.canonize(true), Some(c));
+        assert_eq!(c.canonize(false), Some(c));
+    }
+}
+
+#[test]
+fn abi_transition_is_noop() {
+    use fn_ptr::{abi, abi_transition_is_noop};
+
+    assert!(abi_transition_is_noop::<extern "C" fn(i32), abi!("C")>());
+    assert!(abi_transition_is_noop::<extern "C" fn(i32), abi!("cdecl")>());
+    assert!(!abi_transition_is_noop::<extern "C" fn(i32), abi!("Rust")>());
+    assert!(!abi_transition_is_noop::<fn(), abi!("C-unwind")>());
+
+    #[cfg(not(all(target_arch = "x86", target_os = "windows")))]
+    assert!(abi_transition_is_noop::<extern "system" fn(), abi!("C")>());
+    #[cfg(all(target_arch = "x86", target_os = "windows"))]
+    assert!(abi_transition_is_noop::<
+        extern "system" fn(),
+        abi!("stdcall"),
+    >());
+}
+
+#[test]
+fn requires_trampoline() {
+    use fn_ptr::requires_trampoline;
+
+    assert!(!requires_trampoline::<
+        extern "C" fn(i32),
+        unsafe extern "C" fn(i32),
+    >());
+    assert!(!requires_trampoline::<fn(), fn()>());
+    assert!(requires_trampoline::<fn(), extern "C" fn()>());
+    assert!(requires_trampoline::<extern "C-unwind" fn(), extern "C" fn()>());
+}
+
+#[test]
+fn thiscall_round_trip() {
+    for (s, unwind) in [("thiscall", false), ("thiscall-unwind", true)] {
+        let abi: AbiValue = s.parse().unwrap();
+        assert_eq!(abi, AbiValue::Thiscall { unwind });
+        assert_eq!(abi.to_str(), s);
+    }
+
+    #[cfg(target_arch = "x86")]
+    {
+        use fn_ptr::FnPtr;
+
+        type F = unsafe extern "thiscall" fn(*mut u8);
+        assert_eq!(F::ABI, AbiValue::Thiscall { unwind: false });
+        assert_eq!(<F as FnPtr>::Abi::STR.parse::<AbiValue>().unwrap(), F::ABI);
+        assert_eq!(F::ABI.canonize(false), Some(F::ABI));
+    }
+}
+
+#[test]
+fn marker_conversions() {
+    assert_eq!(AbiValue::from(abi::CUnwind), AbiValue::C { unwind: true });
+    assert_eq!(AbiValue::from(abi::Rust), AbiValue::Rust);
+    assert_eq!(
+        abi::SysV64::try_from(AbiValue::SysV64 { unwind: false }),
+        Ok(abi::SysV64)
+    );
+    assert_eq!(
+        abi::SysV64::try_from(AbiValue::SysV64 { unwind: true }),
+        Err(fn_ptr::AbiMismatch {
+            expected: AbiValue::SysV64 { unwind: false },
+            found: AbiValue::SysV64 { unwind: true },
+        })
+    );
+    assert_eq!(
+        abi::EfiApi::try_from(AbiValue::from(abi::EfiApi)),
+        Ok(abi::EfiApi)
+    );
+}
+
+#[test]
+fn marker_canonical() {
+    assert_eq!(abi::Rust::CANONICAL, Some(AbiValue::Rust));
+    assert_eq!(abi::C::CANONICAL, Some(AbiValue::C { unwind: false }));
+    assert_eq!(abi::CUnwind::CANONICAL, Some(AbiValue::C { unwind: true }));
+    assert_eq!(abi::Cdecl::CANONICAL, Some(AbiValue::C { unwind: false }));
+
+    #[cfg(not(windows))]
+    {
+        assert_eq!(abi::System::CANONICAL, Some(AbiValue::C { unwind: false }));
+        assert_eq!(
+            abi::SystemUnwind::CANONICAL,
+            Some(AbiValue::C { unwind: true })
+        );
+    }
+    #[cfg(all(target_arch = "x86", windows))]
+    assert_eq!(
+        abi::System::CANONICAL,
+        Some(AbiValue::Stdcall { unwind: false })
+    );
+
+    #[cfg(target_arch = "x86")]
+    {
+        assert_eq!(
+            abi::Stdcall::CANONICAL,
+            Some(AbiValue::Stdcall { unwind: false })
+        );
+        assert_eq!(
+            abi::Thiscall::CANONICAL,
+            Some(AbiValue::Thiscall { unwind: false })
+        );
+    }
+    #[cfg(not(target_arch = "x86"))]
+    {
+        assert_eq!(abi::Thiscall::CANONICAL, None);
+        #[cfg(windows)]
+        assert_eq!(abi::Stdcall::CANONICAL, Some(AbiValue::C { unwind: false }));
+        #[cfg(not(windows))]
+        assert_eq!(abi::Stdcall::CANONICAL, None);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        assert_eq!(
+            abi::Win64::CANONICAL,
+            Some(AbiValue::Win64 { unwind: false })
+        );
+        assert_eq!(
+            abi::EfiApi::CANONICAL,
+            Some(AbiValue::Win64 { unwind: false })
+        );
+        assert_eq!(abi::Aapcs::CANONICAL, None);
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    assert_eq!(abi::Win64::CANONICAL, None);
+    #[cfg(target_arch = "aarch64")]
+    assert_eq!(abi::EfiApi::CANONICAL, Some(AbiValue::C { unwind: false }));
+}
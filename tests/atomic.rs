@@ -0,0 +1,43 @@
+#![allow(unpredictable_function_pointer_comparisons)]
+
+use core::sync::atomic::Ordering;
+
+use fn_ptr::{AtomicFnPtr, FnPtr};
+
+fn original(x: i32) -> i32 {
+    x
+}
+
+fn hook(x: i32) -> i32 {
+    x + 1
+}
+
+fn other(x: i32) -> i32 {
+    x * 2
+}
+
+type F = fn(i32) -> i32;
+
+#[test]
+fn compare_exchange_succeeds_for_expected() {
+    let slot = AtomicFnPtr::<F>::new(original);
+    let result = slot.compare_exchange(original, hook, Ordering::AcqRel, Ordering::Acquire);
+    assert_eq!(result, Ok(original as F));
+    assert_eq!(slot.load(Ordering::Acquire).addr(), (hook as F).addr());
+}
+
+#[test]
+fn compare_exchange_fails_for_unexpected() {
+    let slot = AtomicFnPtr::<F>::new(original);
+    let result = slot.compare_exchange(other, hook, Ordering::AcqRel, Ordering::Acquire);
+    assert_eq!(result, Err(original as F));
+    assert_eq!(slot.into_inner().addr(), (original as F).addr());
+}
+
+#[test]
+fn swap_and_store() {
+    let slot = AtomicFnPtr::<F>::new(original);
+    assert_eq!(slot.swap(hook, Ordering::AcqRel)(1), 1);
+    slot.store(other, Ordering::Release);
+    assert_eq!(slot.load(Ordering::Acquire)(3), 6);
+}
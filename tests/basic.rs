@@ -1,6 +1,9 @@
 #![allow(unpredictable_function_pointer_comparisons)]
 
-use fn_ptr::{AbiValue, FnPtr, SafeFnPtr, UnsafeFnPtr, abi, arity, is_extern, is_safe, is_unsafe};
+use fn_ptr::{
+    AbiValue, FnPtr, SafeFnPtr, UnsafeFnPtr, abi, arity, from_discriminant, is_extern, is_safe,
+    is_unsafe, is_unwind,
+};
 
 use static_assertions::assert_type_eq_all;
 
@@ -30,6 +33,30 @@ fn extern_c_fn() {
     assert_eq!(abi::<F>(), AbiValue::C { unwind: false });
 }
 
+#[test]
+fn extern_c_unwind_fn() {
+    type F = extern "C-unwind" fn(i32) -> i32;
+
+    assert_eq!(abi::<F>(), AbiValue::C { unwind: true });
+    assert_ne!(abi::<F>(), abi::<extern "C" fn(i32) -> i32>());
+    assert_eq!(abi::<F>().to_str(), "C-unwind");
+    assert_eq!(
+        abi::<extern "system-unwind" fn()>(),
+        AbiValue::System { unwind: true }
+    );
+}
+
+#[test]
+fn unwind_abis() {
+    assert!(is_unwind::<extern "C-unwind" fn(i32) -> i32>());
+    assert!(!is_unwind::<extern "C" fn(i32) -> i32>());
+    assert!(is_unwind::<unsafe fn()>());
+    assert_eq!(
+        <unsafe extern "system" fn() as FnPtr>::IS_UNWIND,
+        <extern "system" fn() as FnPtr>::ABI.allows_unwind()
+    );
+}
+
 #[test]
 fn zero_arg_fn() {
     type F = fn() -> i32;
@@ -86,6 +113,17 @@ fn addr_and_from_addr() {
     assert_eq!(f, f2);
 }
 
+#[test]
+fn nonzero_addr() {
+    use core::num::NonZeroUsize;
+
+    fn noop() {}
+
+    let f: fn() = noop;
+    assert_eq!(f.nonzero_addr().get(), f.addr());
+    assert_eq!(size_of::<Option<NonZeroUsize>>(), size_of::<usize>());
+}
+
 #[test]
 fn as_ptr_and_from_ptr() {
     unsafe fn mul(a: i32, b: i32) -> i32 {
@@ -103,6 +141,21 @@ fn as_ptr_and_from_ptr() {
     assert_eq!(f, f2);
 }
 
+#[test]
+fn try_from_addr_and_ptr() {
+    extern "C" fn one() -> i32 {
+        1
+    }
+
+    type F = extern "C" fn() -> i32;
+    let f: F = one;
+
+    assert_eq!(unsafe { F::try_from_addr(f.addr()) }, Some(f));
+    assert_eq!(unsafe { F::try_from_ptr(f.as_ptr()) }, Some(f));
+    assert_eq!(unsafe { F::try_from_addr(0) }, None);
+    assert_eq!(unsafe { F::try_from_ptr(core::ptr::null()) }, None);
+}
+
 #[test]
 fn invoke_safe_fnptr() {
     fn square(x: i32) -> i32 {
@@ -130,3 +183,462 @@ fn invoke_unsafe_fnptr() {
         assert_eq!(f.invoke((0,)), 0);
     }
 }
+
+#[test]
+fn rva_round_trip() {
+    fn id(x: u32) -> u32 {
+        x
+    }
+
+    type F = fn(u32) -> u32;
+    let f: F = id;
+
+    let base = f.addr() - 0x1000;
+    assert_eq!(f.rva_from(base), Some(0x1000));
+    assert_eq!(f.rva_from(f.addr() + 1), None);
+
+    let f2: F = unsafe { F::from_rva(base, 0x1000) }.unwrap();
+    assert_eq!(f, f2);
+    assert!(unsafe { F::from_rva(usize::MAX, 1) }.is_none());
+    assert!(unsafe { F::from_rva(base, usize::MAX) }.is_none());
+    assert!(unsafe { F::from_rva(0, 0) }.is_none());
+}
+
+#[test]
+fn extern_c_128_bit_integers() {
+    // 128-bit integers are only FFI-safe with a matching C compiler since Rust 1.77
+    // (see https://blog.rust-lang.org/2024/03/30/i128-layout-update.html).
+    extern "C" fn widen(x: u128) -> i128 {
+        x as i128 * -2
+    }
+
+    type F = extern "C" fn(u128) -> i128;
+    let f: F = widen;
+
+    assert_type_eq_all!(<F as FnPtr>::Args, (u128,));
+    assert_type_eq_all!(<F as FnPtr>::Output, i128);
+
+    assert_eq!(arity::<F>(), 1);
+    assert!(is_extern::<F>());
+    assert_eq!(f.invoke((u128::from(u64::MAX) + 1,)), -(1i128 << 65));
+}
+
+#[test]
+fn as_fn_mut_adapter() {
+    fn double(x: i32) -> i32 {
+        x * 2
+    }
+
+    fn sum_all(values: &[i32], f: &mut dyn FnMut((i32,)) -> i32) -> i32 {
+        values.iter().map(|&v| f((v,))).sum()
+    }
+
+    let f: fn(i32) -> i32 = double;
+    assert_eq!(sum_all(&[1, 2, 3], &mut f.as_fn_mut()), 12);
+}
+
+#[test]
+fn discriminant_round_trip() {
+    type F = unsafe extern "C-unwind" fn(i32, u8) -> i32;
+    type G = fn();
+
+    assert_eq!(
+        from_discriminant(F::DISCRIMINANT),
+        Some((2, AbiValue::C { unwind: true }, false))
+    );
+    assert_eq!(
+        from_discriminant(G::DISCRIMINANT),
+        Some((0, AbiValue::Rust, true))
+    );
+    assert_ne!(F::DISCRIMINANT, G::DISCRIMINANT);
+    assert_eq!(from_discriminant(1 << 17), None);
+    assert_eq!(from_discriminant(0xFF << 8), None);
+}
+
+#[test]
+fn eq_ignoring_abi() {
+    extern "C" fn noop() {}
+
+    let c: extern "C" fn() = noop;
+    let rust: fn() = unsafe { c.with_abi::<fn_ptr::abi::Rust>() };
+    assert!(c.eq_ignoring_abi(&c));
+    assert!(!c.eq_ignoring_abi(&rust));
+
+    // "system" is an alias of "C" everywhere except on 32-bit windows.
+    #[cfg(not(all(windows, target_arch = "x86")))]
+    {
+        let system: extern "system" fn() = unsafe { c.with_abi::<fn_ptr::abi::System>() };
+        assert!(c.eq_ignoring_abi(&system));
+    }
+}
+
+#[test]
+fn is_known_fn() {
+    fn handler(_: i32) {}
+    fn other(_: i32) {
+        std::hint::black_box(());
+    }
+
+    let f: fn(i32) = handler;
+    assert!(f.is(handler as fn(i32)));
+    assert!(f.is(unsafe { f.cast::<unsafe extern "C" fn()>() }));
+    assert!(!f.is(other as fn(i32)));
+}
+
+#[test]
+fn read_prologue() {
+    fn answer() -> i32 {
+        42
+    }
+
+    let f: fn() -> i32 = answer;
+    assert_eq!(f.code_bytes_ptr().addr(), f.code_address());
+
+    let prologue = unsafe { f.read_prologue::<4>() };
+    let first = unsafe { f.code_bytes_ptr().read() };
+    assert_eq!(prologue[0], first);
+    assert_eq!(prologue, unsafe { f.read_prologue::<4>() });
+}
+
+#[test]
+fn prologue_hash() {
+    fn answer() -> i32 {
+        42
+    }
+
+    let f: fn() -> i32 = answer;
+    assert_eq!(unsafe { f.prologue_hash::<0>() }, 0xcbf2_9ce4_8422_2325);
+
+    let hash = unsafe { f.prologue_hash::<8>() };
+    assert!(unsafe { f.prologue_unchanged::<8>(hash) });
+    assert!(!unsafe { f.prologue_unchanged::<8>(hash ^ 1) });
+}
+
+#[test]
+fn cast_size_checked() {
+    extern "C" fn first(a: u32, _b: u32) -> u32 {
+        a
+    }
+
+    type F = extern "C" fn(u32, u32) -> u32;
+    let f: F = first;
+
+    assert_eq!(F::ARGS_SIZE, 8);
+    assert_eq!(<fn()>::ARGS_SIZE, 0);
+
+    let g = unsafe { f.cast_size_checked::<extern "C" fn(i32, f32) -> i32>() };
+    assert_eq!(g.map(|g| g.addr()), Some(f.addr()));
+    assert!(unsafe { f.cast_size_checked::<extern "C" fn(u64, u32) -> u32>() }.is_none());
+    assert!(unsafe { f.cast_size_checked::<extern "C" fn(u32, u32) -> u64>() }.is_none());
+}
+
+#[test]
+fn cast_same() {
+    use fn_ptr::SameSignature;
+
+    extern "C" fn first(a: u32, _b: u32) -> u32 {
+        a
+    }
+
+    type F = extern "C" fn(u32, u32) -> u32;
+    type Alias = extern "C" fn(u32, u32) -> u32;
+
+    fn generic<G: FnPtr + SameSignature<Alias>>(g: G) -> Alias {
+        g.cast_same()
+    }
+
+    let f: F = first;
+    let g: Alias = f.cast_same();
+    assert_eq!(g(4, 5), 4);
+    assert_eq!(generic(f).addr(), f.addr());
+}
+
+#[test]
+fn same_arity_and_abi() {
+    use fn_ptr::{SameAbi, SameArity};
+
+    fn arity_of_both<F: SameArity<G>, G: FnPtr>() -> usize {
+        F::ARITY
+    }
+    fn abi_of_both<F: SameAbi<G>, G: FnPtr>() -> fn_ptr::AbiValue {
+        assert_eq!(F::ABI, G::ABI);
+        F::ABI
+    }
+
+    assert_eq!(
+        arity_of_both::<fn(i32, u8), unsafe extern "system" fn(f64, bool) -> u64>(),
+        2
+    );
+    assert_eq!(arity_of_both::<fn(), extern "C" fn() -> i32>(), 0);
+    assert_eq!(
+        abi_of_both::<extern "C-unwind" fn(u8), unsafe extern "C-unwind" fn() -> i32>(),
+        fn_ptr::AbiValue::C { unwind: true }
+    );
+}
+
+#[test]
+fn tuple_output() {
+    fn split(x: i32) -> (u32, bool) {
+        (x.unsigned_abs(), x < 0)
+    }
+
+    type F = fn(i32) -> (u32, bool);
+    let f: F = split;
+
+    assert_type_eq_all!(<F as FnPtr>::Output, (u32, bool));
+
+    let (abs, negative) = f.invoke((-3,));
+    assert_eq!(abs, 3);
+    assert!(negative);
+}
+
+#[test]
+fn call_addr() {
+    unsafe extern "C" fn checked_div(a: u32, b: u32) -> u32 {
+        a / b
+    }
+
+    let f: unsafe extern "C" fn(u32, u32) -> u32 = checked_div;
+    let result = fn_ptr::call_addr!(
+        SAFETY = "`f` points to `checked_div` and the divisor is non-zero",
+        f.addr() => unsafe extern "C" fn(u32, u32) -> u32,
+        (10, 2)
+    );
+    assert_eq!(result, 5);
+}
+
+#[test]
+fn invoke_refs() {
+    fn describe(name: String, count: usize) -> String {
+        format!("{name}: {count}")
+    }
+
+    let f: fn(String, usize) -> String = describe;
+    let name = String::from("calls");
+    let count = 3;
+    assert_eq!(f.invoke_refs((&name, &count)), "calls: 3");
+    assert_eq!(name, "calls");
+}
+
+#[test]
+fn code_address() {
+    fn noop() {}
+
+    let f: fn() = noop;
+    if cfg!(target_arch = "arm") {
+        assert_eq!(f.code_address(), f.addr() & !1);
+        assert_eq!(f.is_thumb(), f.addr() & 1 != 0);
+    } else {
+        assert_eq!(f.code_address(), f.addr());
+        assert!(!f.is_thumb());
+    }
+}
+
+#[test]
+fn invoke_guarded() {
+    use core::cell::Cell;
+
+    struct Guard<'a>(&'a Cell<bool>);
+    impl Drop for Guard<'_> {
+        fn drop(&mut self) {
+            self.0.set(false);
+        }
+    }
+
+    thread_local! {
+        static HELD: Cell<bool> = const { Cell::new(false) };
+    }
+
+    fn is_held() -> bool {
+        HELD.with(Cell::get)
+    }
+    unsafe fn is_held_unsafe() -> bool {
+        is_held()
+    }
+
+    HELD.with(|held| {
+        held.set(true);
+        assert!((is_held as fn() -> bool).invoke_guarded((), Guard(held)));
+        assert!(!held.get());
+
+        held.set(true);
+        assert!(unsafe { (is_held_unsafe as unsafe fn() -> bool).invoke_guarded((), Guard(held)) });
+        assert!(!held.get());
+    });
+}
+
+#[test]
+fn from_ptr_checked_aligned() {
+    fn noop() {}
+
+    let f: fn() = noop;
+    let checked = unsafe { <fn()>::from_ptr_checked_aligned(f.as_ptr()) };
+    assert_eq!(checked.map(|f| f.addr()), Some(f.addr()));
+    assert!(unsafe { <fn()>::from_ptr_checked_aligned(core::ptr::null()) }.is_none());
+
+    if cfg!(target_arch = "aarch64") {
+        let misaligned = core::ptr::without_provenance(f.addr() + 2);
+        assert!(unsafe { <fn()>::from_ptr_checked_aligned(misaligned) }.is_none());
+    }
+}
+
+#[test]
+fn bind_first() {
+    fn describe(prefix: &'static str, n: i32, flag: bool) -> String {
+        format!("{prefix}: {n} {flag}")
+    }
+    unsafe fn sub(a: i32, b: i32) -> i32 {
+        a - b
+    }
+
+    let bound = (describe as fn(&'static str, i32, bool) -> String).bind_first("value");
+    assert_eq!(bound.first, "value");
+    assert_eq!(bound.invoke((1, true)), "value: 1 true");
+    assert_eq!(bound.invoke((2, false)), "value: 2 false");
+
+    let bound = (sub as unsafe fn(i32, i32) -> i32).bind_first(10);
+    assert_eq!(unsafe { bound.invoke_unsafe((3,)) }, 7);
+}
+
+#[test]
+fn from_union_field() {
+    extern "C" fn on_event(x: i32) -> i32 {
+        x + 1
+    }
+
+    #[repr(C)]
+    union Payload {
+        callback: extern "C" fn(i32) -> i32,
+        value: u64,
+    }
+
+    #[repr(C)]
+    struct Tagged {
+        tag: u32,
+        payload: Payload,
+    }
+
+    let tagged = Tagged {
+        tag: 1,
+        payload: Payload { callback: on_event },
+    };
+    assert_eq!(tagged.tag, 1);
+
+    let offset = core::mem::offset_of!(Tagged, payload);
+    let f = unsafe { <extern "C" fn(i32) -> i32>::from_union_field(&tagged, offset) };
+    assert_eq!(f(1), 2);
+}
+
+#[test]
+fn invoke_black_box() {
+    fn add(a: u64, b: u64) -> u64 {
+        a + b
+    }
+
+    let f: fn(u64, u64) -> u64 = add;
+    assert_eq!(f.invoke_black_box((2, 3)), 5);
+}
+
+#[test]
+fn as_const_ptr() {
+    use fn_ptr::{UntypedFnPtr, as_const_ptr};
+
+    fn a() -> i32 {
+        1
+    }
+    extern "C" fn b(x: i32) -> i32 {
+        x
+    }
+
+    const TABLE: [UntypedFnPtr; 2] = [
+        as_const_ptr(a as fn() -> i32),
+        as_const_ptr(b as extern "C" fn(i32) -> i32),
+    ];
+
+    let a = unsafe { <fn() -> i32>::from_ptr(TABLE[0]) };
+    let b = unsafe { <extern "C" fn(i32) -> i32>::from_ptr(TABLE[1]) };
+    assert_eq!(a(), 1);
+    assert_eq!(b(2), 2);
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn as_sig_casts_to_inline_signature() {
+    extern "C" fn id(x: u64) -> u64 {
+        x
+    }
+
+    let f: extern "C" fn(u64) -> u64 = id;
+    let g = unsafe { fn_ptr::as_sig!(f, unsafe extern "C" fn(i64) -> i64) };
+    fn_ptr::assert_fn_preserves_addr!(f, g);
+    assert_eq!(unsafe { g(-1) }, -1);
+}
+
+#[test]
+fn fn_ptr_returning_fn_ptr() {
+    use fn_ptr::ReturnsFnPtr;
+
+    type Handler = extern "C" fn(i32);
+    type Signal = extern "C" fn(i32, Handler) -> Handler;
+
+    extern "C" fn default_handler(_: i32) {}
+    extern "C" fn signal(_: i32, _: Handler) -> Handler {
+        default_handler
+    }
+
+    fn previous<F: ReturnsFnPtr<Args = (i32, Handler)> + SafeFnPtr>(f: F) -> F::Output {
+        f.invoke((2, default_handler))
+    }
+
+    static_assertions::assert_type_eq_all!(<Signal as FnPtr>::Output, Handler);
+    assert_eq!(<Signal as FnPtr>::ARITY, 2);
+    assert_eq!(<<Signal as FnPtr>::Output as FnPtr>::ARITY, 1);
+
+    let f: Signal = signal;
+    let handler = previous(f);
+    assert!(handler.is(default_handler as Handler));
+}
+
+#[test]
+fn arg_and_output_type_names() {
+    type F = unsafe fn(i32, *const u8, Option<u64>) -> bool;
+    let names: [&str; 3] = F::arg_type_names();
+    assert_eq!(names, ["i32", "*const u8", "core::option::Option<u64>"]);
+    assert_eq!(F::output_type_name(), "bool");
+    assert_eq!(<fn()>::arg_type_names(), [""; 0]);
+    assert_eq!(<fn()>::output_type_name(), "()");
+}
+
+#[test]
+fn write_signature() {
+    fn render<F: FnPtr>() -> String {
+        let mut s = String::new();
+        F::write_signature(&mut s).unwrap();
+        s
+    }
+
+    assert_eq!(
+        render::<unsafe extern "C" fn(i32, u8) -> bool>(),
+        r#"unsafe extern "C" fn(i32, u8) -> bool"#
+    );
+    assert_eq!(render::<fn()>(), "fn()");
+    assert_eq!(
+        render::<extern "system-unwind" fn(*const u8) -> usize>(),
+        r#"extern "system-unwind" fn(*const u8) -> usize"#
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn signature_string() {
+    use fn_ptr::signature_string;
+
+    assert_eq!(
+        signature_string::<unsafe fn(u8) -> u16>(),
+        "unsafe fn(u8) -> u16"
+    );
+    assert_eq!(
+        signature_string::<extern "C" fn(i32)>(),
+        r#"extern "C" fn(i32)"#
+    );
+}
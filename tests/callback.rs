@@ -0,0 +1,34 @@
+use core::mem::{align_of, size_of};
+
+fn_ptr::callback_table! {
+    struct Ops {
+        open: extern "C" fn(i32) -> i32,
+        close: extern "C" fn(),
+    }
+}
+
+extern "C" fn open(fd: i32) -> i32 {
+    fd + 1
+}
+
+#[test]
+fn callback_table_layout() {
+    #[repr(C)]
+    struct Raw {
+        open: *const (),
+        close: *const (),
+    }
+
+    assert_eq!(size_of::<Ops>(), size_of::<Raw>());
+    assert_eq!(align_of::<Ops>(), align_of::<Raw>());
+}
+
+#[test]
+fn callback_table_builder() {
+    let empty = Ops::default();
+    assert!(empty.open.is_none() && empty.close.is_none());
+
+    let ops = Ops::EMPTY.with_open(open);
+    assert_eq!(ops.open.map(|f| f(1)), Some(2));
+    assert!(ops.close.is_none());
+}
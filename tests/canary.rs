@@ -0,0 +1,57 @@
+#![cfg(feature = "std")]
+
+use fn_ptr::Canaried;
+
+#[test]
+fn canaried_invoke_passes_through() {
+    extern "C" fn sum(a: u64, b: u64, c: u64, d: u64, e: u64, f: u64) -> u64 {
+        a + b + c + d + e + f
+    }
+    unsafe fn read(p: *const [u8; 64]) -> u8 {
+        unsafe { (*p)[63] }
+    }
+
+    let f = Canaried(sum as extern "C" fn(u64, u64, u64, u64, u64, u64) -> u64);
+    assert_eq!(f.invoke((1, 2, 3, 4, 5, 6)), 21);
+
+    let buf = [7; 64];
+    let g = Canaried(read as unsafe fn(*const [u8; 64]) -> u8);
+    assert_eq!(unsafe { g.invoke_unsafe((&raw const buf,)) }, 7);
+}
+
+#[test]
+#[cfg(all(
+    debug_assertions,
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+))]
+#[should_panic(expected = "stack canary overwritten")]
+fn canaried_invoke_detects_overwrite() {
+    /// Simulates a callee writing past its frame by scanning the stack above its own frame for the
+    /// guard pattern and clobbering one word of it. If the buffer is not found, nothing is written
+    /// and the test fails because no panic occurs.
+    #[inline(never)]
+    extern "C" fn smash() {
+        const CANARY: usize = usize::from_ne_bytes([0xA5; size_of::<usize>()]);
+        const RUN: usize = 16;
+
+        let local = 0usize;
+        let base = core::hint::black_box(&raw const local).cast_mut();
+        let mut run = 0;
+        for i in 1..2048 {
+            // SAFETY: the stack grows downwards on the tested targets, so the words above `local`
+            // belong to the frames of the callers which are still alive.
+            let word = unsafe { base.add(i) };
+            if unsafe { word.read_volatile() } == CANARY {
+                run += 1;
+                if run == RUN {
+                    unsafe { word.write_volatile(0) };
+                    return;
+                }
+            } else {
+                run = 0;
+            }
+        }
+    }
+
+    Canaried(smash as extern "C" fn()).invoke(());
+}
@@ -0,0 +1,54 @@
+#![cfg(all(feature = "debuginfo", target_os = "linux"))]
+
+use fn_ptr::{FnPtr, SignatureMismatch};
+use object::{Object, ObjectSegment, ObjectSymbol};
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+extern "C" fn fn_ptr_debuginfo_add3(a: i32, b: i32, c: i32) -> i32 {
+    a + b + c
+}
+
+fn load_self() -> (Vec<u8>, usize) {
+    let image = std::fs::read(std::env::current_exe().unwrap()).unwrap();
+    let file = object::File::parse(&*image).unwrap();
+    let symbol = file
+        .symbols()
+        .find(|s| s.name() == Ok("fn_ptr_debuginfo_add3"))
+        .unwrap();
+    // the runtime address of the lowest `PT_LOAD` segment, i.e. `dli_fbase`.
+    let link_base = file.segments().map(|s| s.address()).min().unwrap();
+    let f: extern "C" fn(i32, i32, i32) -> i32 = fn_ptr_debuginfo_add3;
+    let base = f.addr() - usize::try_from(symbol.address() - link_base).unwrap();
+    (image, base)
+}
+
+#[test]
+fn verify_matching_arity() {
+    let (image, base) = load_self();
+    let f: extern "C" fn(i32, i32, i32) -> i32 = fn_ptr_debuginfo_add3;
+    assert_eq!(f.verify_against_debuginfo(&image, base), Ok(()));
+}
+
+#[test]
+fn verify_mismatched_arity() {
+    let (image, base) = load_self();
+    let f: extern "C" fn(i32, i32, i32) -> i32 = fn_ptr_debuginfo_add3;
+    let g: extern "C" fn(i32, i32) -> i32 = unsafe { f.with_args::<(i32, i32)>() };
+    assert_eq!(
+        g.verify_against_debuginfo(&image, base),
+        Err(SignatureMismatch::Arity {
+            expected: 2,
+            found: 3
+        })
+    );
+}
+
+#[test]
+fn verify_invalid_module() {
+    let f: extern "C" fn(i32, i32, i32) -> i32 = fn_ptr_debuginfo_add3;
+    assert_eq!(
+        f.verify_against_debuginfo(b"not a module", 0),
+        Err(SignatureMismatch::InvalidModule)
+    );
+}
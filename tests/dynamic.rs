@@ -0,0 +1,115 @@
+#![cfg(feature = "libffi")]
+
+use fn_ptr::{AbiValue, DynamicCallError, FnPtr, call_dynamic, libffi_abi};
+use libffi::middle::{Type, arg};
+
+extern "C" fn mul_add(a: i32, b: i64, c: f64) -> f64 {
+    f64::from(a) * c + b as f64
+}
+
+#[test]
+fn call_c_fn() {
+    let ptr = (mul_add as extern "C" fn(i32, i64, f64) -> f64).as_ptr();
+    let result: f64 = unsafe {
+        call_dynamic(
+            ptr,
+            AbiValue::C { unwind: false },
+            &[Type::i32(), Type::i64(), Type::f64()],
+            &[arg(&3i32), arg(&1i64), arg(&0.5f64)],
+            Type::f64(),
+        )
+    }
+    .unwrap();
+    assert_eq!(result, 2.5);
+}
+
+#[test]
+#[cfg(target_arch = "x86_64")]
+fn call_win64_and_sysv64_fn() {
+    use fn_ptr::UntypedFnPtr;
+
+    extern "win64" fn sub(a: u32, b: u32) -> u32 {
+        a - b
+    }
+
+    let ptr = sub as extern "win64" fn(u32, u32) -> u32 as UntypedFnPtr;
+    let result: u32 = unsafe {
+        call_dynamic(
+            ptr,
+            AbiValue::Win64 { unwind: false },
+            &[Type::u32(), Type::u32()],
+            &[arg(&10u32), arg(&4u32)],
+            Type::u32(),
+        )
+    }
+    .unwrap();
+    assert_eq!(result, 6);
+
+    #[cfg(unix)]
+    {
+        extern "sysv64" fn neg(a: i8) -> i8 {
+            -a
+        }
+
+        let ptr = neg as extern "sysv64" fn(i8) -> i8 as UntypedFnPtr;
+        let result: i8 = unsafe {
+            call_dynamic(
+                ptr,
+                AbiValue::SysV64 { unwind: true },
+                &[Type::i8()],
+                &[arg(&5i8)],
+                Type::i8(),
+            )
+        }
+        .unwrap();
+        assert_eq!(result, -5);
+    }
+}
+
+#[test]
+fn call_errors() {
+    let ptr = (mul_add as extern "C" fn(i32, i64, f64) -> f64).as_ptr();
+    let err = unsafe {
+        call_dynamic::<f64>(
+            ptr,
+            AbiValue::Rust,
+            &[Type::i32(), Type::i64(), Type::f64()],
+            &[arg(&3i32), arg(&1i64), arg(&0.5f64)],
+            Type::f64(),
+        )
+    }
+    .unwrap_err();
+    assert_eq!(err, DynamicCallError::UnsupportedAbi(AbiValue::Rust));
+
+    let err = unsafe {
+        call_dynamic::<f64>(
+            ptr,
+            AbiValue::C { unwind: false },
+            &[Type::i32(), Type::i64(), Type::f64()],
+            &[arg(&3i32)],
+            Type::f64(),
+        )
+    }
+    .unwrap_err();
+    assert_eq!(
+        err,
+        DynamicCallError::ArgCount {
+            expected: 3,
+            found: 1
+        }
+    );
+}
+
+#[test]
+fn abi_mapping() {
+    assert_eq!(
+        libffi_abi(AbiValue::C { unwind: true }),
+        Some(libffi::middle::ffi_abi_FFI_DEFAULT_ABI)
+    );
+    assert!(libffi_abi(AbiValue::Rust).is_none());
+    assert!(libffi_abi(AbiValue::Vectorcall { unwind: false }).is_none());
+    assert_eq!(
+        libffi_abi(AbiValue::System { unwind: false }).is_some(),
+        AbiValue::System { unwind: false }.canonize(false).is_some()
+    );
+}
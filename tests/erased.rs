@@ -0,0 +1,31 @@
+use fn_ptr::{ErasedCallback, FnPtr};
+
+fn negate(x: i32) -> i32 {
+    -x
+}
+
+#[test]
+fn erase_and_restore() {
+    let f: fn(i32) -> i32 = negate;
+    let erased = ErasedCallback::erase(f);
+    assert_eq!(erased.as_erased().addr(), f.addr());
+    assert_eq!(
+        size_of::<ErasedCallback>(),
+        size_of::<usize>() + size_of::<u64>()
+    );
+
+    let restored = unsafe { erased.restore::<fn(i32) -> i32>() }.unwrap();
+    assert_eq!(restored(3), -3);
+}
+
+#[test]
+fn restore_checks_signature() {
+    let erased = ErasedCallback::erase(negate as fn(i32) -> i32);
+    assert!(unsafe { erased.restore::<fn(i32) -> u32>() }.is_none());
+    assert!(unsafe { erased.restore::<unsafe fn(i32) -> i32>() }.is_none());
+    assert!(unsafe { erased.restore::<extern "C" fn(i32) -> i32>() }.is_none());
+    assert_ne!(
+        erased.signature_hash(),
+        ErasedCallback::erase(negate as unsafe fn(i32) -> i32).signature_hash()
+    );
+}
@@ -0,0 +1,70 @@
+use fn_ptr::{Arity, Tuple, WithAbi, WithSafety, abi, arity, safety};
+
+use static_assertions::assert_type_eq_all;
+
+#[test]
+fn abi_macro_resolves() {
+    assert_type_eq_all!(abi!("C"), abi::C);
+    assert_type_eq_all!(
+        <fn() as WithAbi<abi!("C-unwind")>>::F,
+        extern "C-unwind" fn()
+    );
+}
+
+#[test]
+#[cfg(all(target_arch = "x86_64", not(windows)))]
+fn abi_macro_resolves_sysv64() {
+    assert_type_eq_all!(<fn() as WithAbi<abi!("sysv64")>>::F, extern "sysv64" fn());
+}
+
+#[test]
+fn safety_macro_resolves() {
+    assert_type_eq_all!(safety!(safe), safety::Safe);
+    assert_type_eq_all!(<fn() as WithSafety<safety!(unsafe)>>::F, unsafe fn());
+    assert_type_eq_all!(<unsafe fn() as WithSafety<safety!(true)>>::F, fn());
+}
+
+#[test]
+fn arity_macro_resolves() {
+    assert_type_eq_all!(arity!(0), fn_ptr::arity::A0);
+    assert_type_eq_all!(<(i32, u8, u16) as Tuple>::Arity, arity!(3));
+    assert_eq!(<arity!(6) as Arity>::N, 6);
+}
+
+mod callbacks {
+    fn_ptr::fn_types! {
+        pub Callback = extern "C" fn(i32) -> i32;
+        Unit = fn();
+    }
+}
+
+#[test]
+fn fn_types_macro_declares_aliases() {
+    assert_type_eq_all!(callbacks::Callback, extern "C" fn(i32) -> i32);
+    assert_type_eq_all!(callbacks::CallbackUnsafe, unsafe extern "C" fn(i32) -> i32);
+    assert_type_eq_all!(callbacks::CallbackPtr, Option<extern "C" fn(i32) -> i32>);
+    assert_eq!(
+        size_of::<callbacks::CallbackPtr>(),
+        size_of::<callbacks::Callback>()
+    );
+}
+
+#[test]
+fn arity_marker_of_macro_resolves() {
+    assert_type_eq_all!(fn_ptr::arity_marker_of!(fn()), arity!(0));
+    assert_type_eq_all!(
+        fn_ptr::arity_marker_of!(extern "C" fn(i32, u8) -> u8),
+        arity!(2)
+    );
+    assert_eq!(arity::arity_name(3), Some("A3"));
+    assert_eq!(arity::arity_name(12), Some("A12"));
+    assert_eq!(arity::arity_name(16), Some("A16"));
+    assert_eq!(arity::arity_name(17), None);
+}
+
+#[test]
+fn tuple_arity_matches_arity_macro() {
+    assert_type_eq_all!(<(i32, i32) as Tuple>::Arity, arity!(2));
+    assert_type_eq_all!(<() as Tuple>::Arity, arity!(0), arity::A0);
+    assert_type_eq_all!(arity!(1 + 2), arity::MarkerFor<3>, arity::A3);
+}
@@ -0,0 +1,41 @@
+//! Smoke test for the core api, which has to work on stable without any features enabled.
+
+use fn_ptr::{FnPtr, SafeFnPtr, UnsafeFnPtr, abi, with_abi, with_safety};
+
+use static_assertions::assert_type_eq_all;
+
+#[test]
+fn core_abis_resolve() {
+    type F = fn(i32) -> i32;
+    assert_type_eq_all!(with_abi!("Rust", F), fn(i32) -> i32);
+    assert_type_eq_all!(with_abi!("C", F), extern "C" fn(i32) -> i32);
+    assert_type_eq_all!(with_abi!("C-unwind", F), extern "C-unwind" fn(i32) -> i32);
+    assert_type_eq_all!(with_abi!("system", F), extern "system" fn(i32) -> i32);
+    assert_type_eq_all!(
+        with_abi!("system-unwind", F),
+        extern "system-unwind" fn(i32) -> i32
+    );
+}
+
+#[test]
+fn core_safety_resolves() {
+    type F = extern "C" fn(i32) -> i32;
+    assert_type_eq_all!(with_safety!(unsafe, F), unsafe extern "C" fn(i32) -> i32);
+    assert_type_eq_all!(with_safety!(safe, with_safety!(unsafe, F)), F);
+}
+
+#[test]
+fn core_invoke() {
+    extern "C" fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    let f: extern "C" fn(i32, i32) -> i32 = add;
+    assert_eq!(f.invoke((1, 2)), 3);
+
+    let g: extern "system" fn(i32, i32) -> i32 = unsafe { f.with_abi::<abi::System>() };
+    assert_eq!(g.addr(), f.addr());
+
+    let u = f.as_unsafe();
+    assert_eq!(unsafe { u.invoke((3, 4)) }, 7);
+}
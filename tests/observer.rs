@@ -0,0 +1,57 @@
+#![cfg(feature = "alloc")]
+
+use std::cell::RefCell;
+
+use fn_ptr::{FnPtr, ObserverList};
+
+thread_local! {
+    static LOG: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+fn log(name: &'static str) {
+    LOG.with(|log| log.borrow_mut().push(name));
+}
+
+fn take_log() -> Vec<&'static str> {
+    LOG.with(|log| log.take())
+}
+
+fn first(_: u32) {
+    log("first");
+}
+
+fn second(_: u32) {
+    log("second");
+}
+
+#[test]
+fn notify_in_order() {
+    let mut observers: ObserverList<fn(u32)> = [first as fn(u32), second].into_iter().collect();
+    assert_eq!(observers.len(), 2);
+
+    observers.notify((0,));
+    assert_eq!(take_log(), ["first", "second"]);
+
+    assert!(observers.unsubscribe_by_addr((first as fn(u32)).addr()));
+    assert!(!observers.unsubscribe_by_addr((first as fn(u32)).addr()));
+    observers.notify((0,));
+    assert_eq!(take_log(), ["second"]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn notify_catching_panics() {
+    fn panicking(_: u32) {
+        panic!("observer failed");
+    }
+
+    let mut observers = ObserverList::<fn(u32)>::new();
+    observers.subscribe(first);
+    observers.subscribe(panicking);
+    observers.subscribe(second);
+
+    let panics = observers.notify_catching((0,));
+    assert_eq!(panics.len(), 1);
+    assert_eq!(panics[0].downcast_ref::<&str>(), Some(&"observer failed"));
+    assert_eq!(take_log(), ["first", "second"]);
+}
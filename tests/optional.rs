@@ -0,0 +1,52 @@
+#![allow(unpredictable_function_pointer_comparisons)]
+
+use fn_ptr::{FnPtr, OptionalFnPtr, UntypedFnPtr};
+use static_assertions::assert_eq_size;
+
+type Callback = extern "C" fn(i32) -> i32;
+
+extern "C" fn double(x: i32) -> i32 {
+    x * 2
+}
+
+assert_eq_size!(OptionalFnPtr<Callback>, Callback);
+assert_eq_size!(OptionalFnPtr<Callback>, UntypedFnPtr);
+assert_eq_size!(OptionalFnPtr<fn()>, usize);
+
+#[test]
+fn some_and_none() {
+    let some = OptionalFnPtr::<Callback>::some(double);
+    assert!(some.is_some());
+    assert_eq!(some.as_ptr(), (double as Callback).as_ptr());
+    assert_eq!(some.unwrap()(2), 4);
+
+    let none = OptionalFnPtr::<Callback>::default();
+    assert!(none.is_none());
+    assert!(none.as_ptr().is_null());
+    assert_eq!(none, OptionalFnPtr::NONE);
+    assert_eq!(none.get(), None);
+}
+
+#[test]
+fn from_addr_and_ptr() {
+    let f: Callback = double;
+    let from_addr = unsafe { OptionalFnPtr::<Callback>::from_addr(f.addr()) };
+    assert_eq!(from_addr.get(), Some(f));
+    assert!(unsafe { OptionalFnPtr::<Callback>::from_addr(0) }.is_none());
+    assert!(unsafe { OptionalFnPtr::<Callback>::from_ptr(core::ptr::null()) }.is_none());
+}
+
+#[test]
+fn option_conversions() {
+    let f: Callback = double;
+    let opt: OptionalFnPtr<Callback> = Some(f).into();
+    assert_eq!(Option::from(opt), Some(f));
+    assert_eq!(OptionalFnPtr::from(f), opt);
+    assert_eq!(Option::<Callback>::from(OptionalFnPtr::NONE), None);
+}
+
+#[test]
+#[should_panic = "null function pointer"]
+fn unwrap_none_panics() {
+    let _ = OptionalFnPtr::<fn()>::NONE.unwrap();
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "linkme")]
+
+use fn_ptr::{FnPtr, Signature, register_fn, registered};
+
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+unsafe extern "C" fn noop() {}
+
+register_fn!(add as fn(i32, i32) -> i32);
+register_fn!("c_noop", noop as unsafe extern "C" fn());
+
+#[test]
+fn registered_fns_are_collected() {
+    let entry = registered().iter().find(|f| f.name() == "add").unwrap();
+    assert_eq!(entry.signature(), Signature::of::<fn(i32, i32) -> i32>());
+    assert_eq!(entry.as_ptr(), (add as fn(i32, i32) -> i32).as_ptr());
+
+    let f = unsafe { entry.cast::<fn(i32, i32) -> i32>() }.unwrap();
+    assert_eq!(f(1, 2), 3);
+    assert!(unsafe { entry.cast::<fn(i32) -> i32>() }.is_none());
+}
+
+#[test]
+fn registered_fn_with_explicit_name() {
+    let entry = registered().iter().find(|f| f.name() == "c_noop").unwrap();
+    assert!(!entry.signature().is_safe);
+    assert!(unsafe { entry.cast::<unsafe extern "C" fn()>() }.is_some());
+}
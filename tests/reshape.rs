@@ -0,0 +1,36 @@
+use fn_ptr::{Keep, Reshape, Set, abi, safety};
+
+use static_assertions::assert_type_eq_all;
+
+#[test]
+fn reshape_keep_all() {
+    type F = unsafe extern "C" fn(i32) -> f64;
+    assert_type_eq_all!(<F as Reshape>::F, F);
+}
+
+#[test]
+fn reshape_abi_and_safety() {
+    type F = fn(i32) -> f64;
+    assert_type_eq_all!(
+        <F as Reshape<abi::C, safety::Unsafe>>::F,
+        unsafe extern "C" fn(i32) -> f64
+    );
+}
+
+#[test]
+fn reshape_output_and_args() {
+    type F = extern "system" fn(i32) -> f64;
+    assert_type_eq_all!(
+        <F as Reshape<Keep, Keep, Set<u8>, (u16, u32)>>::F,
+        extern "system" fn(u16, u32) -> u8
+    );
+}
+
+#[test]
+fn reshape_with_set() {
+    type F = unsafe fn();
+    assert_type_eq_all!(
+        <F as Reshape<Set<abi::C>, Set<safety::Safe>, Set<i32>, Set<(i32,)>>>::F,
+        extern "C" fn(i32) -> i32
+    );
+}
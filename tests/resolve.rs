@@ -0,0 +1,89 @@
+use fn_ptr::{FnPtr, SymbolResolver, UntypedFnPtr, resolve_fn};
+
+struct Table(&'static [(&'static str, UntypedFnPtr)]);
+
+impl SymbolResolver for Table {
+    fn resolve(&self, name: &str) -> Option<UntypedFnPtr> {
+        self.0.iter().find(|(n, _)| *n == name).map(|(_, ptr)| *ptr)
+    }
+}
+
+extern "C" fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[test]
+fn resolve_from_custom_resolver() {
+    let table = Table(&[("add", add as *const _), ("null", core::ptr::null())]);
+
+    let f: extern "C" fn(i32, i32) -> i32 = unsafe { resolve_fn(&table, "add") }.unwrap();
+    assert_eq!(f(2, 3), 5);
+    assert_eq!(f.as_ptr(), table.resolve("add").unwrap());
+    assert!(unsafe { resolve_fn::<extern "C" fn(), _>(&table, "null") }.is_none());
+    assert!(unsafe { resolve_fn::<extern "C" fn(), _>(&table, "missing") }.is_none());
+}
+
+#[test]
+#[cfg(all(feature = "libloading", unix))]
+fn resolve_from_libloading() {
+    use core::ffi::{c_char, c_int};
+
+    let lib: libloading::Library = libloading::os::unix::Library::this().into();
+    let abs: unsafe extern "C" fn(c_int) -> c_int = unsafe { resolve_fn(&lib, "abs") }.unwrap();
+    assert_eq!(unsafe { abs(-3) }, 3);
+    assert!(
+        unsafe { resolve_fn::<unsafe extern "C" fn(*const c_char), _>(&lib, "fn_ptr_missing") }
+            .is_none()
+    );
+}
+
+#[test]
+#[cfg(all(feature = "libloading", unix))]
+fn load_fn_from_libloading() {
+    use core::ffi::c_int;
+    use fn_ptr::{from_symbol, load_fn};
+
+    let lib: libloading::Library = libloading::os::unix::Library::this().into();
+    let abs: unsafe extern "C" fn(c_int) -> c_int = unsafe { load_fn(&lib, "abs") }.unwrap();
+    assert_eq!(unsafe { abs(-7) }, 7);
+    assert!(unsafe { load_fn::<extern "C" fn()>(&lib, "fn_ptr_missing") }.is_err());
+
+    let symbol = unsafe { lib.get::<*const ()>(b"abs") }.unwrap();
+    let same: unsafe extern "C" fn(c_int) -> c_int = unsafe { from_symbol(&symbol) };
+    assert_eq!(same.as_ptr(), abs.as_ptr());
+}
+
+#[test]
+fn resolve_decorated_prefers_plain_name() {
+    use fn_ptr::resolve_fn_decorated;
+
+    let table = Table(&[("add", add as *const _)]);
+    let f: extern "C" fn(i32, i32) -> i32 = unsafe { resolve_fn_decorated(&table, "add") }.unwrap();
+    assert_eq!(f(2, 3), 5);
+    assert!(unsafe { resolve_fn_decorated::<extern "C" fn(), _>(&table, "missing") }.is_none());
+    assert_eq!(
+        <extern "C" fn(u8, u64, i32) as FnPtr>::X86_STACK_ARGS_SIZE,
+        16
+    );
+}
+
+#[test]
+#[cfg(all(target_arch = "x86", target_os = "windows"))]
+fn resolve_decorated_stdcall_and_fastcall() {
+    use fn_ptr::resolve_fn_decorated;
+
+    let table = Table(&[
+        ("_Sleep@4", add as *const _),
+        ("@Fast@8", add as *const _),
+        ("_cdecl_fn", add as *const _),
+    ]);
+    assert!(
+        unsafe { resolve_fn_decorated::<extern "system" fn(u32), _>(&table, "Sleep") }.is_some()
+    );
+    assert!(
+        unsafe { resolve_fn_decorated::<extern "fastcall" fn(u8, u32), _>(&table, "Fast") }
+            .is_some()
+    );
+    assert!(unsafe { resolve_fn_decorated::<extern "C" fn(), _>(&table, "cdecl_fn") }.is_some());
+    assert!(unsafe { resolve_fn_decorated::<extern "C" fn(), _>(&table, "Sleep") }.is_none());
+}
@@ -0,0 +1,40 @@
+use core::num::NonZeroI32;
+
+use fn_ptr::{ResultFn, result_fn, status_to_result};
+
+extern "C" fn close(fd: i32) -> i32 {
+    if fd < 0 { 9 } else { 0 }
+}
+
+#[test]
+fn status_codes_map_to_result() {
+    assert_eq!(status_to_result(0), Ok(()));
+    assert_eq!(status_to_result(-1), Err(NonZeroI32::new(-1).unwrap()));
+
+    let close: ResultFn<extern "C" fn(i32) -> i32> = result_fn!(close);
+    assert_eq!(close.invoke((3,)), Ok(()));
+    assert_eq!(close.invoke((-1,)), Err(NonZeroI32::new(9).unwrap()));
+}
+
+#[test]
+fn custom_mapping() {
+    #[derive(Debug, PartialEq)]
+    struct BadFd;
+
+    let close = result_fn!(close as extern "C" fn(i32) -> i32, |code| match code {
+        0 => Ok(()),
+        _ => Err(BadFd),
+    });
+    assert_eq!(close.invoke((-1,)), Err(BadFd));
+}
+
+#[test]
+fn unsafe_fn_ptr() {
+    unsafe extern "C" fn deref(p: *const i32) -> i32 {
+        unsafe { *p }
+    }
+
+    let deref = result_fn!(deref as unsafe extern "C" fn(*const i32) -> i32);
+    let value = 0;
+    assert_eq!(unsafe { deref.invoke_unsafe((&raw const value,)) }, Ok(()));
+}
@@ -0,0 +1,93 @@
+use fn_ptr::{
+    BuildFn, FnPtr, Reshape, Set, abi, safety, with_abi, with_args, with_output, with_safety,
+};
+
+/// Deterministic sample of addresses, including edge cases and odd (e.g. thumb) addresses.
+fn addresses() -> impl Iterator<Item = usize> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let random = core::iter::repeat_with(move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        #[allow(clippy::cast_possible_truncation)]
+        let addr = state as usize;
+        addr
+    });
+    [1, 2, 3, 0xFFF, 0x1001, usize::MAX, usize::MAX - 1]
+        .into_iter()
+        .chain(random.take(1000))
+        .filter(|&addr| addr != 0)
+}
+
+#[test]
+fn from_addr_round_trip() {
+    for addr in addresses() {
+        // SAFETY: the function pointers are never called.
+        let f = unsafe { <extern "C" fn(i32) -> i32>::from_addr(addr) };
+        assert_eq!(f.addr(), addr);
+        assert_eq!(f.as_ptr().addr(), addr);
+
+        let g = unsafe { <unsafe fn()>::from_ptr(f.as_ptr()) };
+        assert_eq!(g.addr(), addr);
+    }
+}
+
+#[test]
+fn cast_preserves_addr() {
+    for addr in addresses() {
+        let f = unsafe { <extern "C" fn(i32) -> i32>::from_addr(addr) };
+
+        let g: unsafe extern "C" fn(u32) -> u32 = unsafe { f.cast() };
+        assert_eq!(g.addr(), addr);
+        let h: fn() = unsafe { g.cast() };
+        assert_eq!(h.addr(), addr);
+        assert_eq!(f.as_unsafe().addr(), addr);
+        assert_eq!(unsafe { g.as_safe() }.addr(), addr);
+    }
+}
+
+/// Casts `f` to `G` and back, asserting the address is preserved in both directions.
+fn assert_round_trip<F: FnPtr, G: FnPtr>(f: F) {
+    let g: G = unsafe { f.cast() };
+    assert_eq!(FnPtr::addr(&g), FnPtr::addr(&f));
+    let back: F = unsafe { g.cast() };
+    assert_eq!(FnPtr::addr(&back), FnPtr::addr(&f));
+    assert!(back == f);
+}
+
+/// Round trips `f` through every dimension a function pointer type can be rewritten in.
+fn assert_round_trip_grid<F>(f: F)
+where
+    F: FnPtr + Reshape<abi::C, safety::Unsafe, Set<i8>>,
+{
+    assert_round_trip::<F, with_abi!(abi::Rust, F)>(f);
+    assert_round_trip::<F, with_abi!(abi::C, F)>(f);
+    assert_round_trip::<F, with_abi!(abi::CUnwind, F)>(f);
+    assert_round_trip::<F, with_abi!(abi::System, F)>(f);
+    assert_round_trip::<F, with_abi!(abi::SystemUnwind, F)>(f);
+    assert_round_trip::<F, with_safety!(safety::Safe, F)>(f);
+    assert_round_trip::<F, with_safety!(safety::Unsafe, F)>(f);
+    assert_round_trip::<F, with_output!((), F)>(f);
+    assert_round_trip::<F, with_output!(u128, F)>(f);
+    assert_round_trip::<F, with_args!((u8,), F)>(f);
+    assert_round_trip::<F, with_args!((u64, *const u8, f32), F)>(f);
+    assert_round_trip::<F, <F as Reshape<abi::C, safety::Unsafe, Set<i8>>>::F>(f);
+    assert_round_trip::<F, <(u16, u16) as BuildFn<safety::Unsafe, abi::System, u8>>::F>(f);
+    assert_round_trip::<F, <F as FnPtr>::Safe>(f);
+    assert_round_trip::<F, <F as FnPtr>::Unsafe>(f);
+    assert_round_trip::<F, <F as FnPtr>::WithRust>(f);
+    assert_round_trip::<F, <F as FnPtr>::WithC>(f);
+}
+
+#[test]
+fn cast_round_trips_through_every_dimension() {
+    for addr in addresses() {
+        unsafe {
+            assert_round_trip_grid(<fn()>::from_addr(addr));
+            assert_round_trip_grid(<unsafe fn(i32) -> i32>::from_addr(addr));
+            assert_round_trip_grid(<extern "C" fn(i32, u8) -> u64>::from_addr(addr));
+            assert_round_trip_grid(<unsafe extern "C-unwind" fn(*mut u8)>::from_addr(addr));
+            assert_round_trip_grid(<extern "system" fn(f64, f32, i8) -> f32>::from_addr(addr));
+        }
+    }
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "serde")]
+
+use fn_ptr::{AbiValue, abi};
+
+#[test]
+fn abi_value_round_trip() {
+    for abi in [
+        AbiValue::Rust,
+        AbiValue::C { unwind: false },
+        AbiValue::System { unwind: true },
+        AbiValue::EfiApi,
+    ] {
+        let json = serde_json::to_string(&abi).unwrap();
+        assert_eq!(json, format!("\"{}\"", abi.to_str()));
+        assert_eq!(serde_json::from_str::<AbiValue>(&json).unwrap(), abi);
+    }
+}
+
+#[test]
+fn abi_value_rejects_unknown() {
+    let err = serde_json::from_str::<AbiValue>("\"C-unwinds\"").unwrap_err();
+    assert!(err.to_string().contains("C-unwinds"));
+    assert!(serde_json::from_str::<AbiValue>("1").is_err());
+}
+
+#[test]
+fn abi_markers() {
+    assert_eq!(
+        serde_json::to_string(&abi::SysV64Unwind).unwrap(),
+        "\"sysv64-unwind\""
+    );
+    assert_eq!(
+        serde_json::from_str::<abi::CUnwind>("\"C-unwind\"").unwrap(),
+        abi::CUnwind
+    );
+    assert!(serde_json::from_str::<abi::CUnwind>("\"C\"").is_err());
+}
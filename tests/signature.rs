@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+
+use fn_ptr::{AbiValue, FnPtr, Signature};
+
+#[test]
+fn signature_from_consts() {
+    type F = extern "C-unwind" fn(i32, u8) -> u64;
+
+    let sig = F::signature();
+    assert_eq!(sig.arity, F::ARITY);
+    assert_eq!(sig.abi, AbiValue::C { unwind: true });
+    assert!(sig.is_safe);
+    assert_eq!(sig.is_extern(), F::IS_EXTERN);
+    assert_eq!(sig, Signature::of::<F>());
+    assert!(!<unsafe fn()>::signature().is_extern());
+}
+
+#[test]
+fn signature_matches() {
+    let sig = <fn(i32) -> i32>::signature();
+    assert!(sig.matches::<fn(u8)>());
+    assert!(!sig.matches::<unsafe fn(u8)>());
+    assert!(!sig.matches::<fn()>());
+    assert!(!sig.matches::<extern "C" fn(u8)>());
+
+    let set: HashSet<Signature> = [
+        <fn(i32)>::signature(),
+        <fn(u8)>::signature(),
+        <extern "C" fn(u8)>::signature(),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn signature_display() {
+    assert_eq!(<fn()>::signature().to_string(), "fn()");
+    assert_eq!(
+        <unsafe fn(i32) -> i32>::signature().to_string(),
+        "unsafe fn(_)"
+    );
+    assert_eq!(
+        <extern "system" fn(i32, u8, u16)>::signature().to_string(),
+        r#"extern "system" fn(_, _, _)"#
+    );
+}
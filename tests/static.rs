@@ -18,3 +18,57 @@ fn non_static_argument_does_not_implement_static_fn_ptr() {
     type F = fn(&i32);
     assert_not_impl_all!(F: StaticFnPtr);
 }
+
+#[test]
+fn type_ids_of_signature() {
+    use core::any::TypeId;
+
+    type F = extern "C" fn(i32, u8) -> u64;
+    assert_eq!(F::args_type_id(), TypeId::of::<(i32, u8)>());
+    assert_eq!(F::output_type_id(), TypeId::of::<u64>());
+    assert_eq!(<fn()>::args_type_id(), TypeId::of::<()>());
+    assert_ne!(F::args_type_id(), <fn(u8, i32) -> u64>::args_type_id());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn signature_mangled_encodes_shape() {
+    extern "C" fn callback(_: i32) {}
+    unsafe extern "system" fn check(_: *const u8) -> bool {
+        true
+    }
+    fn lookup(_: &'static str, _: &'static mut [u8], _: Option<u32>) -> usize {
+        0
+    }
+
+    let f: extern "C" fn(i32) = callback;
+    let g: unsafe extern "system" fn(*const u8) -> bool = check;
+    let h: fn(&'static str, &'static mut [u8], Option<u32>) -> usize = lookup;
+    assert_eq!(f.signature_mangled(), "FviE");
+    assert_eq!(g.signature_mangled(), "U6unsafeU6systemFbPKhE");
+    assert_eq!(
+        h.signature_mangled(),
+        "U4RustFmRK3strR4[u8]25core::option::Option<u32>E"
+    );
+    assert_ne!(
+        f.signature_mangled(),
+        ((|_| {}) as fn(u32)).signature_mangled()
+    );
+
+    let no_args: fn() = || {};
+    let unit_arg: fn(()) = |()| {};
+    assert_eq!(no_args.signature_mangled(), "U4RustFvvE");
+    assert_eq!(unit_arg.signature_mangled(), "U4RustFvu4unitE");
+    assert_ne!(no_args.signature_mangled(), unit_arg.signature_mangled());
+}
+
+#[test]
+fn arg_type_ids() {
+    use core::any::TypeId;
+
+    type F = extern "C" fn(i32, u8) -> u64;
+    assert_eq!(F::arg_type_ids(), [TypeId::of::<i32>(), TypeId::of::<u8>()]);
+    assert_eq!(F::arg_type_ids(), <unsafe fn(i32, u8)>::arg_type_ids());
+    assert_ne!(F::arg_type_ids(), <fn(u8, i32)>::arg_type_ids());
+    assert_eq!(<fn()>::arg_type_ids(), []);
+}
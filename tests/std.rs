@@ -0,0 +1,132 @@
+#![cfg(feature = "std")]
+
+use fn_ptr::{FnPtr, SafeFnPtr, WrongThread};
+
+#[test]
+fn invoke_checked_thread() {
+    fn double(x: i32) -> i32 {
+        x * 2
+    }
+
+    let f: fn(i32) -> i32 = double;
+    let main = std::thread::current().id();
+    assert_eq!(f.invoke_checked_thread((2,), main), Ok(4));
+
+    let other = std::thread::spawn(move || f.invoke_checked_thread((2,), main))
+        .join()
+        .unwrap();
+    assert!(matches!(other, Err(WrongThread { expected, .. }) if expected == main));
+}
+
+#[test]
+#[cfg(any(unix, windows))]
+fn module_path() {
+    fn local() {}
+
+    let path = (local as fn()).module_path().unwrap();
+    let exe = std::env::current_exe().unwrap();
+    assert_eq!(path.file_name(), exe.file_name());
+}
+
+#[test]
+fn memoized_caches_results() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    fn square(x: u32) -> u32 {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        x * x
+    }
+
+    let mut memo = fn_ptr::Memoized::new(square as fn(u32) -> u32);
+    assert_eq!(memo.invoke((3,)), 9);
+    assert_eq!(memo.invoke((3,)), 9);
+    assert_eq!(memo.invoke((4,)), 16);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+    assert_eq!(memo.cached_len(), 2);
+
+    memo.clear();
+    assert_eq!(memo.invoke((3,)), 9);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+    assert!(memo.fn_ptr().is(square as fn(u32) -> u32));
+}
+
+#[test]
+fn panic_to_code_maps_panics() {
+    use fn_ptr::PanicToCode;
+
+    fn parse(digit: u8) -> i32 {
+        match digit {
+            b'0'..=b'9' => i32::from(digit - b'0'),
+            _ => panic!("not a digit"),
+        }
+    }
+
+    let f = PanicToCode::new(parse as fn(u8) -> i32, |payload| {
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"not a digit"));
+        -1
+    });
+    assert_eq!(f.invoke((b'7',)), 7);
+    assert_eq!(f.invoke((b'x',)), -1);
+}
+
+#[test]
+fn closure_trampoline_dispatches_to_closure() {
+    use fn_ptr::ClosureTrampoline;
+    use std::{cell::Cell, rc::Rc};
+
+    struct First;
+    struct Second;
+
+    let calls = Rc::new(Cell::new(0));
+    let calls2 = calls.clone();
+    let (add, guard) =
+        ClosureTrampoline::<extern "C" fn(i32, i32) -> i32, First>::register(move |(a, b)| {
+            calls2.set(calls2.get() + 1);
+            a + b
+        });
+    let offset = 10;
+    let (sub, other) =
+        ClosureTrampoline::<extern "C" fn(i32, i32) -> i32, Second>::register(move |(a, b)| {
+            a - b + offset
+        });
+    assert_ne!(add.as_ptr(), sub.as_ptr());
+    assert_eq!(add(1, 2), 3);
+    assert_eq!(sub(1, 2), 9);
+    assert_eq!(calls.get(), 1);
+
+    assert!(ClosureTrampoline::<extern "C" fn(i32, i32) -> i32, First>::is_registered());
+    drop(guard);
+    assert!(!ClosureTrampoline::<extern "C" fn(i32, i32) -> i32, First>::is_registered());
+    assert!(ClosureTrampoline::<extern "C" fn(i32, i32) -> i32, Second>::is_registered());
+    drop(other);
+}
+
+#[test]
+fn closure_trampoline_slot_is_reusable_and_unwinds() {
+    use fn_ptr::ClosureTrampoline;
+
+    type F = unsafe extern "C-unwind" fn() -> u8;
+
+    let (f, guard) = ClosureTrampoline::<F>::register(|()| 1);
+    assert_eq!(unsafe { f() }, 1);
+    drop(guard);
+
+    let guard = ClosureTrampoline::<F>::register(|()| panic!("boom")).1;
+    let result = std::panic::catch_unwind(|| unsafe { f() });
+    assert_eq!(*result.unwrap_err().downcast::<&str>().unwrap(), "boom");
+    drop(guard);
+
+    let result = std::panic::catch_unwind(|| unsafe { f() });
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic = "already registered"]
+fn closure_trampoline_slot_holds_one_closure() {
+    use fn_ptr::ClosureTrampoline;
+
+    let _first = ClosureTrampoline::<extern "C" fn()>::register(|()| {});
+    let _second = ClosureTrampoline::<extern "C" fn()>::register(|()| {});
+}
@@ -0,0 +1,79 @@
+use fn_ptr::{AbortOnPanic, SafeReplacement};
+
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+type CTarget = extern "C" fn(i32) -> i32;
+type CUnwindTarget = extern "C-unwind" fn(i32) -> i32;
+
+#[test]
+fn replacement_for_unwind_target() {
+    assert_impl_all!(fn(i32) -> i32: SafeReplacement<CUnwindTarget>);
+    assert_impl_all!(extern "C" fn(i32) -> i32: SafeReplacement<CUnwindTarget>);
+    assert_impl_all!(extern "C-unwind" fn(i32) -> i32: SafeReplacement<fn(i32)>);
+}
+
+#[test]
+fn replacement_for_non_unwind_target() {
+    assert_impl_all!(extern "C" fn(i32) -> i32: SafeReplacement<CTarget>);
+    assert_impl_all!(extern "system" fn(i32) -> i32: SafeReplacement<CTarget>);
+    assert_not_impl_any!(fn(i32) -> i32: SafeReplacement<CTarget>);
+    assert_not_impl_any!(extern "C-unwind" fn(i32) -> i32: SafeReplacement<CTarget>);
+    assert_impl_all!(AbortOnPanic<fn(i32) -> i32>: SafeReplacement<CTarget>);
+}
+
+#[test]
+fn abort_on_panic_invoke() {
+    fn double(x: i32) -> i32 {
+        x * 2
+    }
+
+    let f = AbortOnPanic(double as fn(i32) -> i32);
+    assert_eq!(f.invoke((4,)), 8);
+}
+
+#[test]
+fn c_thunk() {
+    use fn_ptr::{FnPtr, c_thunk};
+
+    fn add<T: core::ops::Add<Output = T>>(a: T, b: T) -> T {
+        a + b
+    }
+    fn unit() {}
+
+    let f: extern "C" fn(i64, i64) -> i64 = c_thunk!(add::<i64> as fn(i64, i64) -> i64);
+    assert_eq!(f(40, 2), 42);
+    assert!(f.is(f));
+    assert!(!f.is(add::<i64> as fn(i64, i64) -> i64));
+
+    let g: extern "C" fn() = c_thunk!(unit as fn());
+    g();
+}
+
+#[test]
+fn unwind_preserving_conversions() {
+    use fn_ptr::{assert_unwind_preserving, changes_unwind};
+
+    assert!(changes_unwind::<fn(i32) -> i32, CTarget>());
+    assert!(!changes_unwind::<fn(i32) -> i32, CUnwindTarget>());
+    assert!(!changes_unwind::<CTarget, fn(i32) -> i32>());
+
+    assert_unwind_preserving!(fn(i32) -> i32, CUnwindTarget);
+    assert_unwind_preserving!(CTarget, CTarget);
+}
+
+#[test]
+fn allows_unwind_in_const_context() {
+    use fn_ptr::{Abi, FnPtr, abi};
+
+    const C_UNWIND: bool = fn_ptr::abi::<CUnwindTarget>().allows_unwind();
+    const C: bool = <<CTarget as FnPtr>::Abi as Abi>::ALLOWS_UNWIND;
+    assert_eq!(
+        [
+            C_UNWIND,
+            C,
+            abi::Rust::ALLOWS_UNWIND,
+            abi::EfiApi::ALLOWS_UNWIND
+        ],
+        [true, false, true, false]
+    );
+}
@@ -0,0 +1,42 @@
+use core::{
+    sync::atomic::{AtomicUsize, Ordering},
+    task::{RawWaker, RawWakerVTable, Waker},
+};
+
+use fn_ptr::waker_vtable;
+
+static WAKES: AtomicUsize = AtomicUsize::new(0);
+static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe fn clone(data: *const ()) -> RawWaker {
+    RawWaker::new(data, &VTABLE)
+}
+fn wake(_: *const ()) {
+    WAKES.fetch_add(1, Ordering::SeqCst);
+    DROPS.fetch_add(1, Ordering::SeqCst);
+}
+fn wake_by_ref(_: *const ()) {
+    WAKES.fetch_add(1, Ordering::SeqCst);
+}
+unsafe fn drop(_: *const ()) {
+    DROPS.fetch_add(1, Ordering::SeqCst);
+}
+
+static VTABLE: RawWakerVTable = waker_vtable!(
+    clone as unsafe fn(*const ()) -> RawWaker,
+    wake as fn(*const ()),
+    wake_by_ref as fn(*const ()),
+    drop as unsafe fn(*const ()),
+);
+
+#[test]
+fn waker_from_vtable() {
+    let waker = unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) };
+    waker.wake_by_ref();
+    let cloned = waker.clone();
+    cloned.wake();
+    core::mem::drop(waker);
+
+    assert_eq!(WAKES.load(Ordering::SeqCst), 2);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 2);
+}
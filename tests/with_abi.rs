@@ -19,3 +19,46 @@ fn with_rust_abi() {
     type F = extern "C" fn(i32);
     assert_type_eq_all!(with_abi!("Rust", F), fn(i32));
 }
+
+#[test]
+fn abi_projections() {
+    use fn_ptr::FnPtr;
+
+    type F = unsafe extern "system" fn(u8) -> u16;
+    assert_type_eq_all!(<F as FnPtr>::WithRust, unsafe fn(u8) -> u16);
+    assert_type_eq_all!(<F as FnPtr>::WithC, unsafe extern "C" fn(u8) -> u16);
+    assert_type_eq_all!(<fn() as FnPtr>::WithC, extern "C" fn());
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+#[test]
+fn with_efiapi_abi() {
+    use fn_ptr::{AbiValue, FnPtr};
+
+    type F = extern "C" fn(*mut u8) -> usize;
+    type G = with_abi!("efiapi", F);
+    assert_type_eq_all!(G, extern "efiapi" fn(*mut u8) -> usize);
+    assert_type_eq_all!(<G as FnPtr>::Abi, fn_ptr::abi!("efiapi"));
+    assert_eq!(<G as FnPtr>::ABI, AbiValue::EfiApi);
+    assert_eq!("efiapi".parse::<AbiValue>(), Ok(AbiValue::EfiApi));
+}
+
+#[test]
+fn with_unwind() {
+    use fn_ptr::{WithUnwind, with_unwind};
+
+    assert_type_eq_all!(
+        with_unwind!(true, extern "C" fn(i32)),
+        extern "C-unwind" fn(i32)
+    );
+    assert_type_eq_all!(
+        with_unwind!(false, unsafe extern "system-unwind" fn() -> u8),
+        unsafe extern "system" fn() -> u8
+    );
+    assert_type_eq_all!(with_unwind!(false, extern "C" fn()), extern "C" fn());
+    assert_type_eq_all!(<fn(u8) as WithUnwind<true>>::F, fn(u8));
+    assert_type_eq_all!(
+        with_unwind!(true, Option<extern "C" fn()>),
+        Option<extern "C-unwind" fn()>
+    );
+}
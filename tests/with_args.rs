@@ -22,3 +22,159 @@ fn with_args_to_singleton_tuple() {
     type F = fn(i32) -> i32;
     assert_type_eq_all!(with_args!((u64,), F), fn(u64) -> i32);
 }
+
+#[test]
+fn with_args_to_reference_args() {
+    type F = extern "C" fn(i32) -> i32;
+    assert_type_eq_all!(
+        with_args!((&'static u8, *mut u8), F),
+        extern "C" fn(&'static u8, *mut u8) -> i32
+    );
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn with_args_to_reference_args_in_generic_code() {
+    use fn_ptr::{FnPtr, SafeFnPtr};
+
+    fn wrap<'a, F: FnPtr>(f: F) -> with_args!((&'a u8,), F) {
+        unsafe { f.cast() }
+    }
+
+    fn first(x: &u8) -> u8 {
+        *x
+    }
+
+    let f: fn(u8) -> u8 = |x| x;
+    let g: fn(&'static u8) -> u8 = wrap(f);
+    fn_ptr::assert_fn_preserves_addr!(f, g);
+
+    let h: fn(&'static u8) -> u8 = first;
+    assert_eq!(h.invoke((&7,)), 7);
+}
+
+#[test]
+fn flatten_args_one_level() {
+    use fn_ptr::{Concat, Flatten, flatten_args};
+
+    type F = unsafe fn((i32, u8), (f32,), ()) -> u64;
+    assert_type_eq_all!(flatten_args!(F), unsafe fn(i32, u8, f32) -> u64);
+    assert_type_eq_all!(flatten_args!(fn(((u8, u16),))), fn((u8, u16)));
+    assert_type_eq_all!(flatten_args!(fn()), fn());
+
+    assert_eq!(((1, 2u8), (3.0f32,), ()).flatten(), (1, 2u8, 3.0f32));
+    assert_eq!((1,).concat((2u8, 3u16)), (1, 2u8, 3u16));
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn cast_arg_transparent() {
+    use fn_ptr::FnPtr;
+
+    fn_ptr::transparent_arg! {
+        #[derive(Clone, Copy)]
+        struct Handle(i32);
+    }
+
+    extern "C" fn raw(handle: i32, extra: u8) -> i32 {
+        handle + i32::from(extra)
+    }
+
+    let f: extern "C" fn(i32, u8) -> i32 = raw;
+    let g: extern "C" fn(Handle, u8) -> i32 = f.cast_arg_transparent();
+    fn_ptr::assert_fn_preserves_addr!(f, g);
+    assert_eq!(g(Handle(40), 2), 42);
+
+    let same: extern "C" fn(i32, u8) -> i32 = f.cast_arg_transparent();
+    assert_eq!(same(1, 1), 2);
+}
+
+#[test]
+fn swap_args() {
+    use fn_ptr::{SwapArgs, swap_args};
+
+    type F = unsafe extern "C" fn(i32, u8, f32) -> bool;
+    assert_type_eq_all!(
+        swap_args!(0, 2, F),
+        unsafe extern "C" fn(f32, u8, i32) -> bool
+    );
+    assert_type_eq_all!(
+        swap_args!(2, 1, F),
+        unsafe extern "C" fn(i32, f32, u8) -> bool
+    );
+    assert_type_eq_all!(swap_args!(1, 1, F), F);
+    assert_type_eq_all!(<fn(u8, u16) as SwapArgs<0, 1>>::F, fn(u16, u8));
+    assert_type_eq_all!(swap_args!(0, 1, Option<fn(u8, u16)>), Option<fn(u16, u8)>);
+}
+
+#[test]
+fn with_arg() {
+    use fn_ptr::{WithArg, with_arg};
+
+    type F = fn(i32, u8, i32) -> bool;
+    assert_type_eq_all!(with_arg!(1, u16, F), fn(i32, u16, i32) -> bool);
+    assert_type_eq_all!(with_arg!(0, u8, F), fn(u8, u8, i32) -> bool);
+    assert_type_eq_all!(
+        <unsafe extern "C" fn(u8) as WithArg<0, *const u8>>::F,
+        unsafe extern "C" fn(*const u8)
+    );
+    assert_type_eq_all!(with_arg!(2, (), Option<F>), Option<fn(i32, u8, ()) -> bool>);
+}
+
+#[test]
+fn prepend_and_append_arg() {
+    use fn_ptr::{AppendArg, PrependArg, append_arg, prepend_arg};
+
+    struct Ctx;
+
+    type F = unsafe extern "C" fn(i32) -> u8;
+    assert_type_eq_all!(
+        prepend_arg!(*mut Ctx, F),
+        unsafe extern "C" fn(*mut Ctx, i32) -> u8
+    );
+    assert_type_eq_all!(
+        append_arg!(*mut Ctx, F),
+        unsafe extern "C" fn(i32, *mut Ctx) -> u8
+    );
+    assert_type_eq_all!(<fn() as PrependArg<u8>>::F, fn(u8));
+    assert_type_eq_all!(<fn() as AppendArg<u8>>::F, fn(u8));
+    assert_type_eq_all!(
+        append_arg!(u16, prepend_arg!(u8, Option<fn(i32)>)),
+        Option<fn(u8, i32, u16)>
+    );
+}
+
+#[test]
+fn pop_arg() {
+    use fn_ptr::{AppendArg, PopArg, SplitLast, pop_arg};
+    use static_assertions::assert_not_impl_any;
+
+    type F = unsafe extern "C" fn(i32, u8) -> bool;
+    assert_type_eq_all!(pop_arg!(F), unsafe extern "C" fn(i32) -> bool);
+    assert_type_eq_all!(<F as PopArg>::Popped, u8);
+    assert_type_eq_all!(pop_arg!(fn(u8)), fn());
+    assert_type_eq_all!(<<F as PopArg>::F as AppendArg<u8>>::F, F);
+    assert_type_eq_all!(
+        pop_arg!(Option<F>),
+        Option<unsafe extern "C" fn(i32) -> bool>
+    );
+    type F0 = fn();
+    assert_not_impl_any!(F0: PopArg);
+
+    assert_eq!((1, 2u8, 3u16).split_last(), ((1, 2u8), 3u16));
+    assert_eq!(<(i32, u8)>::join_last((1,), 2), (1, 2));
+}
+
+#[test]
+fn reverse_args() {
+    use fn_ptr::{Reverse, ReverseArgs, reverse_args};
+
+    type F = extern "C" fn(i32, u8, bool) -> u16;
+    assert_type_eq_all!(reverse_args!(F), extern "C" fn(bool, u8, i32) -> u16);
+    assert_type_eq_all!(reverse_args!(reverse_args!(F)), F);
+    assert_type_eq_all!(<fn() as ReverseArgs>::F, fn());
+    assert_type_eq_all!(<unsafe fn(u8) as ReverseArgs>::F, unsafe fn(u8));
+    assert_type_eq_all!(reverse_args!(Option<fn(u8, u16)>), Option<fn(u16, u8)>);
+
+    assert_eq!((1, 2u8, true).reverse(), (true, 2u8, 1));
+}
@@ -13,3 +13,47 @@ fn with_output_on_rust_fn_pointer() {
     type F = fn(i32) -> i32;
     assert_type_eq_all!(with_output!((), F), fn(i32) -> ());
 }
+
+#[cfg(feature = "test-util")]
+#[test]
+fn with_output_in_fully_generic_fn() {
+    use fn_ptr::FnPtr;
+
+    fn set_out<F: FnPtr, T>(f: F) -> with_output!(T, F) {
+        unsafe { f.cast() }
+    }
+
+    extern "C" fn answer() -> i32 {
+        42
+    }
+
+    let f: extern "C" fn() -> i32 = answer;
+    let g = set_out::<_, u32>(f);
+    assert_type_eq_all!(
+        with_output!(u32, extern "C" fn() -> i32),
+        extern "C" fn() -> u32
+    );
+    assert_eq!(g(), 42);
+    fn_ptr::assert_fn_preserves_addr!(f, g);
+}
+
+#[test]
+fn compose_fn() {
+    use fn_ptr::ComposeFn;
+
+    extern "C" fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+    fn describe(n: i32) -> String {
+        format!("sum={n}")
+    }
+
+    type F = extern "C" fn(i32, i32) -> i32;
+    type G = fn(i32) -> String;
+    assert_type_eq_all!(<F as ComposeFn<G>>::F, extern "C" fn(i32, i32) -> String);
+
+    let composed = (add as F).compose(describe as G);
+    assert_eq!(composed((1, 2)), "sum=3");
+    let twice = (describe as G).compose((|s: String| s.len()) as fn(String) -> usize);
+    assert_eq!(twice((10,)), 6);
+}
@@ -17,3 +17,56 @@ fn make_unsafe() {
 
     assert_type_eq_all!(make_unsafe!(SafeF), UnsafeF);
 }
+
+#[cfg(feature = "test-util")]
+#[test]
+fn reinterpret_safety() {
+    use fn_ptr::{SafeFnPtr, UnsafeFnPtr};
+
+    extern "C" fn double(x: i32) -> i32 {
+        x * 2
+    }
+
+    let f: extern "C" fn(i32) -> i32 = double;
+    let u: unsafe extern "C" fn(i32) -> i32 = f.reinterpret_safety();
+    fn_ptr::assert_fn_preserves_addr!(f, u);
+    assert_eq!(unsafe { u(2) }, 4);
+
+    let same: extern "C" fn(i32) -> i32 = SafeFnPtr::reinterpret_safety(&f);
+    assert_eq!(same(3), 6);
+
+    let s: extern "C" fn(i32) -> i32 = unsafe { UnsafeFnPtr::reinterpret_safety(&u) };
+    assert_eq!(s(4), 8);
+}
+
+#[test]
+fn safety_projections() {
+    use fn_ptr::FnPtr;
+
+    type F = extern "C" fn(i32) -> i32;
+    assert_type_eq_all!(<F as FnPtr>::Unsafe, make_unsafe!(F));
+    assert_type_eq_all!(<F as FnPtr>::Safe, F);
+    assert_type_eq_all!(
+        <<F as FnPtr>::Unsafe as FnPtr>::Safe,
+        extern "C" fn(i32) -> i32
+    );
+}
+
+#[test]
+fn rewrites_preserve_option() {
+    use fn_ptr::{with_abi, with_args, with_output, with_safety};
+
+    type Callback = Option<extern "C" fn(i32)>;
+    assert_type_eq_all!(make_unsafe!(Callback), Option<unsafe extern "C" fn(i32)>);
+    assert_type_eq_all!(make_safe!(Option<unsafe fn()>), Option<fn()>);
+    assert_type_eq_all!(
+        with_safety!(unsafe, Callback),
+        Option<unsafe extern "C" fn(i32)>
+    );
+    assert_type_eq_all!(
+        with_abi!("C-unwind", Callback),
+        Option<extern "C-unwind" fn(i32)>
+    );
+    assert_type_eq_all!(with_output!(u8, Callback), Option<extern "C" fn(i32) -> u8>);
+    assert_type_eq_all!(with_args!((), Callback), Option<extern "C" fn()>);
+}